@@ -1,4 +1,6 @@
-pub fn part1(input: &str) -> String {
+use lib::Answer;
+
+pub fn part1(input: &str) -> Answer {
     let data: Vec<_> = input
         .split('\n')
         .filter_map(|x| str::parse::<u32>(x).ok())
@@ -8,10 +10,10 @@ pub fn part1(input: &str) -> String {
         .zip(data.iter())
         .filter(|(later, earlier)| later > earlier)
         .count()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let data: Vec<_> = input
         .split('\n')
         .filter_map(|x| str::parse::<u32>(x).ok())
@@ -21,7 +23,7 @@ pub fn part2(input: &str) -> String {
         .zip(data.iter())
         .filter(|(later, earlier)| later > earlier)
         .count()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
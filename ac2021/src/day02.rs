@@ -1,4 +1,6 @@
-use crate::p02::Command::{Down, Forward, Up};
+use crate::day02::Command::{Down, Forward, Up};
+
+use lib::Answer;
 
 enum Command {
     Forward(u128),
@@ -6,7 +8,7 @@ enum Command {
     Up(u128),
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let commands: Vec<Command> = parse_commands(input);
     let mut depth = 0u128;
     let mut pos = 0u128;
@@ -17,10 +19,10 @@ pub fn part1(input: &str) -> String {
             Up(x) => depth -= x,
         }
     }
-    (depth * pos).to_string()
+    (depth * pos).into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let commands: Vec<Command> = parse_commands(input);
     let mut depth = 0u128;
     let mut pos = 0u128;
@@ -35,7 +37,7 @@ pub fn part2(input: &str) -> String {
             Up(x) => aim -= x,
         }
     }
-    (depth * pos).to_string()
+    (depth * pos).into()
 }
 
 fn parse_commands(input: &str) -> Vec<Command> {
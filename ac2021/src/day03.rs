@@ -1,4 +1,6 @@
-pub fn part1(input: &str) -> String {
+use lib::Answer;
+
+pub fn part1(input: &str) -> Answer {
     let mut occurences = vec![];
     input.lines().filter(|x| !x.is_empty()).for_each(|number| {
         number.chars().enumerate().for_each(|(i, digit)| {
@@ -30,7 +32,7 @@ pub fn part1(input: &str) -> String {
         2,
     )
     .expect("invalid digit");
-    (epsilon * gamma).to_string()
+    (epsilon * gamma).into()
 }
 
 fn get_digit(input: &[&str], index: usize) -> Vec<char> {
@@ -48,7 +50,7 @@ fn get_most_common(input: &[char]) -> char {
     }
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let mut ogr_data: Vec<&str> = input.lines().collect();
     let mut co2_data: Vec<&str> = input.lines().collect();
     for i in 0..input.lines().next().expect("empty input").len() {
@@ -63,7 +65,7 @@ pub fn part2(input: &str) -> String {
     }
     let ogr = u32::from_str_radix(ogr_data.first().expect("invalid ogr"), 2).expect("invalid ogr");
     let co2 = u32::from_str_radix(co2_data.first().expect("invalid co2"), 2).expect("invalid co2");
-    (ogr * co2).to_string()
+    (ogr * co2).into()
 }
 
 #[cfg(test)]
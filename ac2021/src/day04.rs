@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::Sum;
+use std::ops::Mul;
+use std::str::FromStr;
+
+use lib::Answer;
+
+#[derive(Clone, Debug)]
+pub struct Board<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    marked: Vec<bool>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    diagonal_marks: [usize; 2],
+    has_won: bool,
+}
+
+impl<T: FromStr> FromStr for Board<T> {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<Vec<T>, _>>()
+                    .map_err(|_| "invalid number")
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let width = rows.first().ok_or("empty board")?.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return Err("rows of different lengths");
+        }
+        Ok(Self::new(width, rows.into_iter().flatten().collect()))
+    }
+}
+
+impl<T> Board<T> {
+    /// A board `width` cells wide, with `cells` given row by row.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "cells do not fill whole rows"
+        );
+        let height = cells.len() / width;
+        Self {
+            width,
+            height,
+            marked: vec![false; cells.len()],
+            cells,
+            row_marks: vec![0; height],
+            column_marks: vec![0; width],
+            diagonal_marks: [0; 2],
+            has_won: false,
+        }
+    }
+
+    /// Marks the cell at `index`, returning whether that completed a line.
+    fn mark(&mut self, index: usize, diagonals: bool) -> bool {
+        if std::mem::replace(&mut self.marked[index], true) {
+            return false;
+        }
+        let (row, column) = (index / self.width, index % self.width);
+        self.row_marks[row] += 1;
+        self.column_marks[column] += 1;
+        let mut complete =
+            self.row_marks[row] == self.width || self.column_marks[column] == self.height;
+        if diagonals && self.width == self.height {
+            for (diagonal, on_diagonal) in [row == column, row + column == self.width - 1]
+                .into_iter()
+                .enumerate()
+            {
+                if on_diagonal {
+                    self.diagonal_marks[diagonal] += 1;
+                    complete |= self.diagonal_marks[diagonal] == self.width;
+                }
+            }
+        }
+        complete
+    }
+
+    fn unmarked(&self) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .zip(&self.marked)
+            .filter(|(_, marked)| !**marked)
+            .map(|(cell, _)| cell)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Win<T> {
+    pub board: usize,
+    pub draw: T,
+    pub score: T,
+}
+
+/// A game over any number of boards of any size. Every number points at the
+/// cells holding it, so a draw only touches the boards it appears on.
+pub struct Bingo<T> {
+    boards: Vec<Board<T>>,
+    cells: HashMap<T, Vec<(usize, usize)>>,
+    diagonals: bool,
+}
+
+impl<T> Bingo<T>
+where
+    T: Copy + Eq + Hash + Sum + Mul<Output = T>,
+{
+    pub fn new(boards: Vec<Board<T>>, diagonals: bool) -> Self {
+        let mut cells: HashMap<T, Vec<(usize, usize)>> = HashMap::new();
+        for (board_index, board) in boards.iter().enumerate() {
+            for (cell_index, cell) in board.cells.iter().enumerate() {
+                cells
+                    .entry(*cell)
+                    .or_default()
+                    .push((board_index, cell_index));
+            }
+        }
+        Self {
+            boards,
+            cells,
+            diagonals,
+        }
+    }
+
+    /// Plays until the draws run out or every board has won, returning the
+    /// wins in order. Boards winning on the same draw are ordered by index.
+    pub fn play(&mut self, draws: impl IntoIterator<Item = T>) -> Vec<Win<T>> {
+        let mut wins = vec![];
+        for draw in draws {
+            if wins.len() == self.boards.len() {
+                break;
+            }
+            for (board_index, cell_index) in self.cells.get(&draw).into_iter().flatten() {
+                let board = &mut self.boards[*board_index];
+                if !board.has_won && board.mark(*cell_index, self.diagonals) {
+                    board.has_won = true;
+                    wins.push(Win {
+                        board: *board_index,
+                        draw,
+                        score: board.unmarked().copied().sum::<T>() * draw,
+                    });
+                }
+            }
+        }
+        wins
+    }
+}
+
+fn parse_input<T: FromStr>(input: &str) -> Result<(Vec<T>, Vec<Board<T>>), String> {
+    let mut blocks = input.split("\n\n");
+    let nums: Vec<T> = blocks
+        .next()
+        .ok_or_else(|| "No block".to_string())?
+        .trim()
+        .split(',')
+        .map(str::parse::<T>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| "invalid number".to_string())?;
+    let boards: Vec<Board<T>> = blocks
+        .map(str::parse)
+        .collect::<Result<Vec<Board<T>>, &str>>()
+        .map_err(str::to_string)?;
+    Ok((nums, boards))
+}
+
+fn wins(input: &str) -> Vec<Win<u32>> {
+    let (nums, boards) = parse_input(input).expect("parsing failed");
+    Bingo::new(boards, false).play(nums)
+}
+
+pub fn part1(input: &str) -> Answer {
+    wins(input).first().expect("no board won").score.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    wins(input).last().expect("no board won").score.into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = r"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+ 2 22 18 10 14
+ 0 11  8 16 21
+12 13 23 15 17
+ 3  6 26  9 24
+ 7  5 20 19  4";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "4512");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "1924");
+    }
+
+    #[test]
+    fn test_win_sequence() {
+        let wins: Vec<_> = wins(DATA)
+            .into_iter()
+            .map(|win| (win.board, win.draw))
+            .collect();
+        assert_eq!(wins, [(2, 24), (0, 16), (1, 13)]);
+    }
+
+    #[test]
+    fn test_diagonals_and_shapes() {
+        let input = "-1,5,9,2\n\n-1 2 3\n4 5 6\n7 8 9\n\n2 6\n-1 8";
+        let (nums, boards) = parse_input::<i64>(input).unwrap();
+        let square = Win {
+            board: 1,
+            draw: 2,
+            score: (6 + 8) * 2,
+        };
+        assert_eq!(
+            Bingo::new(boards.clone(), false).play(nums.clone()),
+            [square]
+        );
+        assert_eq!(
+            Bingo::new(boards, true).play(nums),
+            [
+                Win {
+                    board: 0,
+                    draw: 9,
+                    score: (2 + 3 + 4 + 6 + 7 + 8) * 9
+                },
+                square
+            ]
+        );
+
+        let input = "1,3,5\n\n1 2\n3 4\n5 6\n\n1\n2";
+        let (nums, boards) = parse_input::<u8>(input).unwrap();
+        assert_eq!(
+            Bingo::new(boards, true).play(nums),
+            [
+                Win {
+                    board: 1,
+                    draw: 1,
+                    score: 2
+                },
+                Win {
+                    board: 0,
+                    draw: 5,
+                    score: (2 + 4 + 6) * 5
+                }
+            ]
+        );
+    }
+}
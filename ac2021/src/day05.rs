@@ -0,0 +1,363 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use lib::grid::{Cells, SparseGrid};
+use lib::image::{blend, Image};
+use lib::Answer;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl FromStr for Point {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut nums = s.split(',').map(|x| x.parse::<i64>().unwrap());
+        Ok(Self {
+            x: nums.next().unwrap(),
+            y: nums.next().unwrap(),
+        })
+    }
+}
+
+impl Point {
+    const fn same_axis(&self, other: &Self) -> bool {
+        self.x == other.x || self.y == other.y
+    }
+}
+
+const fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (b, a) = (a % b, b);
+    }
+    a
+}
+
+const fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Line {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut points = s.split(" -> ").map(|x| x.parse::<Point>().unwrap());
+        Ok(Self {
+            start: points.next().unwrap(),
+            end: points.next().unwrap(),
+        })
+    }
+}
+
+impl Line {
+    const fn straight(&self) -> bool {
+        self.start.same_axis(&self.end)
+    }
+
+    /// The smallest integer step from `start` towards `end`, and how many
+    /// of those steps the line takes. A single point steps along the x axis
+    /// zero times.
+    fn step(&self) -> (Point, i64) {
+        let (dx, dy) = (self.end.x - self.start.x, self.end.y - self.start.y);
+        let steps = gcd(dx.abs(), dy.abs());
+        if steps == 0 {
+            return (Point { x: 1, y: 0 }, 0);
+        }
+        (
+            Point {
+                x: dx / steps,
+                y: dy / steps,
+            },
+            steps,
+        )
+    }
+
+    /// Every lattice point on the line, whatever its slope.
+    fn get_points(&self) -> impl Iterator<Item = Point> + '_ {
+        let (step, steps) = self.step();
+        (0..=steps).map(move |i| Point {
+            x: self.start.x + i * step.x,
+            y: self.start.y + i * step.y,
+        })
+    }
+}
+
+/// A line in a form where parallel lines share a direction: `start + k *
+/// direction` for `k` in `0..=steps`, with `direction` pointing right (or
+/// down, when vertical).
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    start: (i128, i128),
+    direction: (i128, i128),
+    steps: i128,
+}
+
+impl From<&Line> for Segment {
+    fn from(line: &Line) -> Self {
+        let (step, steps) = line.step();
+        let (start, step) = if (step.x, step.y) < (0, 0) {
+            (
+                line.end,
+                Point {
+                    x: -step.x,
+                    y: -step.y,
+                },
+            )
+        } else {
+            (line.start, step)
+        };
+        Self {
+            start: (i128::from(start.x), i128::from(start.y)),
+            direction: (i128::from(step.x), i128::from(step.y)),
+            steps: i128::from(steps),
+        }
+    }
+}
+
+impl Segment {
+    /// Identifies the infinite line through the segment.
+    const fn line(&self) -> (i128, i128, i128) {
+        (
+            self.direction.0,
+            self.direction.1,
+            cross(self.start, self.direction),
+        )
+    }
+
+    /// The position of a point on the line, counted in steps.
+    fn position(&self, (x, y): (i128, i128)) -> i128 {
+        let (dx, dy) = self.direction;
+        (x * dx + y * dy).div_euclid(dx * dx + dy * dy)
+    }
+
+    fn span(&self) -> (i128, i128) {
+        let first = self.position(self.start);
+        (first, first + self.steps)
+    }
+
+    /// The lattice point where two non-parallel segments cross, if any.
+    fn crossing(&self, other: &Self) -> Option<(i128, i128)> {
+        let offset = (other.start.0 - self.start.0, other.start.1 - self.start.1);
+        let denominator = cross(self.direction, other.direction);
+        let (k, l) = (
+            cross(offset, other.direction),
+            cross(offset, self.direction),
+        );
+        if k % denominator != 0 || l % denominator != 0 {
+            return None;
+        }
+        let (k, l) = (k / denominator, l / denominator);
+        ((0..=self.steps).contains(&k) && (0..=other.steps).contains(&l)).then_some((
+            self.start.0 + k * self.direction.0,
+            self.start.1 + k * self.direction.1,
+        ))
+    }
+}
+
+/// Sorts and merges overlapping (or touching) inclusive ranges.
+fn merge(mut spans: Vec<(i128, i128)>) -> Vec<(i128, i128)> {
+    spans.sort_unstable();
+    let mut merged: Vec<(i128, i128)> = vec![];
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some((_, last)) if start <= *last + 1 => *last = (*last).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Counts the points covered by at least two lines without visiting them:
+/// collinear lines overlap along a run of points, every other pair meets in
+/// at most one.
+fn count_overlaps(lines: &[Line]) -> u64 {
+    let segments: Vec<Segment> = lines.iter().map(Segment::from).collect();
+    let mut runs: HashMap<(i128, i128, i128), Vec<(i128, i128)>> = HashMap::new();
+    let mut crossings: HashMap<(i128, i128), Vec<Segment>> = HashMap::new();
+    for (i, a) in segments.iter().enumerate() {
+        for b in &segments[i + 1..] {
+            if a.direction != b.direction {
+                if let Some(point) = a.crossing(b) {
+                    crossings.entry(point).or_default().extend([*a, *b]);
+                }
+            } else if a.line() == b.line() {
+                let ((a_start, a_end), (b_start, b_end)) = (a.span(), b.span());
+                let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                if start <= end {
+                    runs.entry(a.line()).or_default().push((start, end));
+                }
+            }
+        }
+    }
+    let runs: HashMap<_, _> = runs
+        .into_iter()
+        .map(|(line, spans)| (line, merge(spans)))
+        .collect();
+    let in_runs: i128 = runs
+        .values()
+        .flatten()
+        .map(|(start, end)| end - start + 1)
+        .sum();
+    let in_run = |segment: &Segment, point| {
+        let position = segment.position(point);
+        runs.get(&segment.line()).is_some_and(|spans| {
+            let after = spans.partition_point(|(start, _)| *start <= position);
+            after > 0 && spans[after - 1].1 >= position
+        })
+    };
+    // A crossing outside every run is a new point; one inside runs on
+    // several lines has been counted once per line.
+    let correction: i128 = crossings
+        .iter()
+        .map(|(point, segments)| {
+            let lines: HashSet<_> = segments
+                .iter()
+                .filter(|segment| in_run(segment, *point))
+                .map(Segment::line)
+                .collect();
+            match lines.len() {
+                0 => 1,
+                n => 1 - n as i128,
+            }
+        })
+        .sum();
+    u64::try_from(in_runs + correction).expect("a count is never negative")
+}
+
+#[derive(Clone, Debug)]
+struct Seabed {
+    field: SparseGrid<u32>,
+}
+
+impl Seabed {
+    fn new(lines: &[Line]) -> Self {
+        let mut field = SparseGrid::new();
+        for line in lines {
+            for Point { x, y } in line.get_points() {
+                *field.get_or_insert_with((x, y), || 0) += 1;
+            }
+        }
+        Self { field }
+    }
+
+    fn get_greater_than(&self, n: u32) -> usize {
+        self.field.iter().filter(|(_, x)| **x > n).count()
+    }
+}
+
+fn process_input(input: &str) -> Vec<Line> {
+    input
+        .lines()
+        .map(|line| line.parse::<Line>().unwrap())
+        .collect()
+}
+
+pub fn vents(input: &str) -> Image {
+    let seabed = Seabed::new(&process_input(input));
+    let most = seabed.field.iter().map(|(_, n)| *n).max().unwrap_or(0);
+    let field = seabed.field.to_dense(0);
+    Image::from_fn(field.width(), field.height(), |x, y| {
+        match field[(x as i64, y as i64)] {
+            0 => [16, 16, 32],
+            1 => [40, 80, 160],
+            n => blend(
+                [230, 190, 60],
+                [230, 60, 60],
+                f64::from(n - 2) / f64::from(most.max(3) - 2),
+            ),
+        }
+    })
+}
+
+fn straight_lines(input: &str) -> Vec<Line> {
+    process_input(input)
+        .into_iter()
+        .filter(Line::straight)
+        .collect()
+}
+
+pub fn part1(input: &str) -> Answer {
+    Seabed::new(&straight_lines(input))
+        .get_greater_than(1)
+        .into()
+}
+
+pub fn part1_analytic(input: &str) -> Answer {
+    count_overlaps(&straight_lines(input)).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    Seabed::new(&process_input(input))
+        .get_greater_than(1)
+        .into()
+}
+
+pub fn part2_analytic(input: &str) -> Answer {
+    count_overlaps(&process_input(input)).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = r"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "5");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "12");
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        let input = include_str!("../input/p05");
+        for data in [DATA, input] {
+            assert_eq!(part1_analytic(data), part1(data));
+            assert_eq!(part2_analytic(data), part2(data));
+        }
+    }
+
+    #[test]
+    fn test_any_slope() {
+        let line: Line = "1,7 -> 7,-2".parse().unwrap();
+        assert_eq!(
+            line.get_points().collect::<Vec<_>>(),
+            [(1, 7), (3, 4), (5, 1), (7, -2)].map(|(x, y)| Point { x, y })
+        );
+        let data = "1,7 -> 7,-2\n0,0 -> 10,5\n9,-5 -> 3,4\n4,4 -> 4,4\n3,4 -> 3,4\n0,3 -> 6,0";
+        assert_eq!(part2(data), "3");
+        assert_eq!(part2_analytic(data), "3");
+    }
+
+    #[test]
+    fn test_huge_coordinates() {
+        let data = "0,0 -> 3000000000000,3000000000000
+1000000000000,0 -> 1000000000000,2000000000000
+500000000000,500000000000 -> 2500000000000,2500000000000
+0,3000000000000 -> 3000000000000,0
+1000000000000,1500000000000 -> 1000000000000,1500000000000";
+        assert_eq!(part2_analytic(data), "2000000000003");
+    }
+}
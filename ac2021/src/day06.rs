@@ -0,0 +1,254 @@
+use lib::num::{BigUint, Natural, Overflow};
+use lib::Answer;
+
+fn parse_input(input: &str) -> Vec<u8> {
+    input
+        .lines()
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|x| x.parse().unwrap())
+        .collect()
+}
+
+pub fn part1_individual(input: &str) -> Answer {
+    let mut data = parse_input(input);
+    for _ in 0..80 {
+        let zeroes = bytecount::count(&data, 0);
+        data.resize(data.len() + zeroes, 9);
+        for val in &mut data {
+            *val = match *val {
+                0 => 6,
+                x => x - 1,
+            }
+        }
+    }
+    data.len().into()
+}
+
+/// How long a fish waits between spawns after its first, and how long a
+/// newborn waits before its first.
+#[derive(Debug, Clone, Copy)]
+pub struct Timers {
+    pub reset: usize,
+    pub newborn: usize,
+}
+
+pub const TIMERS: Timers = Timers {
+    reset: 6,
+    newborn: 8,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Matrix<N> {
+    size: usize,
+    cells: Vec<N>,
+}
+
+impl<N: Natural> Matrix<N> {
+    fn identity(size: usize) -> Self {
+        let mut cells = vec![N::zero(); size * size];
+        for i in 0..size {
+            cells[i * size + i] = N::from_u64(1);
+        }
+        Self { size, cells }
+    }
+
+    /// The change of the timer brackets over one day: every bracket moves
+    /// down one, and the fish at zero go back to `reset` and spawn newborns.
+    fn day(timers: Timers) -> Self {
+        assert!(
+            timers.reset <= timers.newborn,
+            "fish reset past the newborn timer"
+        );
+        let size = timers.newborn + 1;
+        let mut cells = vec![N::zero(); size * size];
+        for timer in 0..timers.newborn {
+            cells[timer * size + timer + 1] = N::from_u64(1);
+        }
+        let spawning = N::from_u64(1);
+        cells[timers.newborn * size] = spawning.clone();
+        cells[timers.reset * size] = cells[timers.reset * size].add(&spawning).unwrap();
+        Self { size, cells }
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in 0..self.size {
+            for column in 0..self.size {
+                let mut total = N::zero();
+                for i in 0..self.size {
+                    let product = self.cells[row * self.size + i]
+                        .mul(&other.cells[i * self.size + column])?;
+                    total = total.add(&product)?;
+                }
+                cells.push(total);
+            }
+        }
+        Ok(Self {
+            size: self.size,
+            cells,
+        })
+    }
+
+    /// Raises the matrix to `exponent` by repeated squaring.
+    fn pow(&self, mut exponent: u64) -> Result<Self, Overflow> {
+        let mut result = Self::identity(self.size);
+        let mut square = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&square)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                square = square.mul(&square)?;
+            }
+        }
+        Ok(result)
+    }
+
+    fn apply(&self, vector: &[N]) -> Result<Vec<N>, Overflow> {
+        self.cells
+            .chunks(self.size)
+            .map(|row| {
+                row.iter()
+                    .zip(vector)
+                    .try_fold(N::zero(), |total, (a, b)| total.add(&a.mul(b)?))
+            })
+            .collect()
+    }
+}
+
+/// Counts the fish after `days` as a linear recurrence over the timer
+/// brackets, in a logarithmic number of matrix products.
+pub fn population<N: Natural>(fish: &[u8], days: u64, timers: Timers) -> Result<N, Overflow> {
+    let brackets: Vec<N> = (0..=timers.newborn)
+        .map(|timer| u8::try_from(timer).map_or(0, |timer| bytecount::count(fish, timer)))
+        .map(|count| N::from_u64(count as u64))
+        .collect();
+    assert_eq!(
+        N::sum(&brackets)?,
+        N::from_u64(fish.len() as u64),
+        "a fish has a timer above {}",
+        timers.newborn
+    );
+    N::sum(&Matrix::day(timers).pow(days)?.apply(&brackets)?)
+}
+
+pub fn part1(input: &str) -> Answer {
+    population::<u64>(&parse_input(input), 80, TIMERS)
+        .expect("lanternfish overflowed u64")
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    population::<u64>(&parse_input(input), 256, TIMERS)
+        .expect("lanternfish overflowed u64")
+        .into()
+}
+
+/// Counts the fish after `days`, tracking how many are at each timer value.
+fn simulate<N: Natural>(fish: &[u8], days: usize) -> Result<N, Overflow> {
+    let mut brackets: Vec<N> = (0..9)
+        .map(|x| N::from_u64(bytecount::count(fish, x) as u64))
+        .collect();
+    for _ in 0..days {
+        brackets.rotate_left(1);
+        brackets[6] = brackets[6].add(&brackets[8])?;
+    }
+    N::sum(&brackets)
+}
+
+pub fn part2_simulated(input: &str) -> Answer {
+    simulate::<u64>(&parse_input(input), 256)
+        .expect("lanternfish overflowed u64")
+        .into()
+}
+
+pub fn part2_bigint(input: &str) -> Answer {
+    population::<BigUint>(&parse_input(input), 256, TIMERS)
+        .expect("bigint cannot overflow")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use lib::num::Modular;
+
+    use super::*;
+
+    const DATA: &str = r"3,4,3,1,2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "5934");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "26984457539");
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        let input = include_str!("../input/p06");
+        for data in [DATA, input] {
+            assert_eq!(part1_individual(data), part1(data));
+            assert_eq!(part2_simulated(data), part2(data));
+            assert_eq!(part2_bigint(data), part2(data));
+        }
+    }
+
+    #[test]
+    fn test_long_runs() {
+        let fish = parse_input(DATA);
+        assert_eq!(simulate::<u64>(&fish, 256), Ok(26_984_457_539));
+        assert_eq!(simulate::<u64>(&fish, 10_000), Err(Overflow));
+        assert_eq!(population::<u64>(&fish, 10_000, TIMERS), Err(Overflow));
+        let many = population::<BigUint>(&fish, 10_000, TIMERS)
+            .unwrap()
+            .to_string();
+        assert_eq!(many.len(), 380);
+        assert!(many.starts_with("12295220913725202160"), "{many}");
+        assert!(many.ends_with("15511445328619725056"), "{many}");
+        assert_eq!(
+            simulate::<BigUint>(&fish, 10_000).unwrap().to_string(),
+            many
+        );
+    }
+
+    #[test]
+    fn test_modular_population() {
+        type Mod = Modular<1_000_000_007>;
+        let fish = parse_input(DATA);
+        assert_eq!(
+            population::<Mod>(&fish, 256, TIMERS).unwrap().value(),
+            26_984_457_539 % 1_000_000_007
+        );
+        assert_eq!(
+            population::<Mod>(&fish, 1_000_000_000_000, TIMERS)
+                .unwrap()
+                .value(),
+            995_077_479
+        );
+    }
+
+    #[test]
+    fn test_other_timers() {
+        let timers = Timers {
+            reset: 2,
+            newborn: 4,
+        };
+        assert_eq!(population::<u64>(&parse_input(DATA), 40, timers), Ok(5183));
+        let timers = Timers {
+            reset: 2,
+            newborn: 2,
+        };
+        assert_eq!(population::<u64>(&[0], 1, timers), Ok(2));
+        let timers = Timers {
+            reset: 6,
+            newborn: 300,
+        };
+        assert_eq!(population::<u64>(&[0, 255], 1, timers), Ok(3));
+    }
+}
@@ -0,0 +1,221 @@
+use lib::Answer;
+
+fn parse_input(input: &str) -> Vec<u32> {
+    input
+        .lines()
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|x| x.parse().unwrap())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub target: u32,
+    pub cost: u64,
+}
+
+fn total_alignment_cost<F: Fn(u32, u32) -> u64>(
+    positions: &[u32],
+    target: u32,
+    cost_function: &F,
+) -> u64 {
+    positions
+        .iter()
+        .map(|current| cost_function(*current, target))
+        .sum()
+}
+
+fn alignment<F: Fn(u32, u32) -> u64>(
+    positions: &[u32],
+    target: u32,
+    cost_function: &F,
+) -> Alignment {
+    Alignment {
+        target,
+        cost: total_alignment_cost(positions, target, cost_function),
+    }
+}
+
+/// The cheapest of `targets`, preferring the lowest target on ties.
+fn cheapest<F: Fn(u32, u32) -> u64>(
+    positions: &[u32],
+    targets: impl IntoIterator<Item = u32>,
+    cost_function: &F,
+) -> Alignment {
+    targets
+        .into_iter()
+        .map(|target| alignment(positions, target, cost_function))
+        .min_by_key(|alignment| (alignment.cost, alignment.target))
+        .expect("no targets to align to")
+}
+
+fn brute_force_alignment<F: Fn(u32, u32) -> u64>(
+    positions: &[u32],
+    cost_function: &F,
+) -> Alignment {
+    let min = *positions.iter().min().unwrap();
+    let max = *positions.iter().max().unwrap();
+    cheapest(positions, min..=max, cost_function)
+}
+
+/// Finds the cheapest target for any cost function that is convex in the
+/// target, by binary search on the sign of the total cost's slope. A sum of
+/// convex costs is convex, so a flat step can only happen at the minimum.
+pub fn convex_alignment<F: Fn(u32, u32) -> u64>(positions: &[u32], cost_function: &F) -> Alignment {
+    let mut low = *positions.iter().min().unwrap();
+    let mut high = *positions.iter().max().unwrap();
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if total_alignment_cost(positions, middle, cost_function)
+            <= total_alignment_cost(positions, middle + 1, cost_function)
+        {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    alignment(positions, low, cost_function)
+}
+
+pub fn linear_cost(current: u32, target: u32) -> u64 {
+    u64::from(current.abs_diff(target))
+}
+
+/// Any median minimises the total linear distance.
+fn median_alignment(positions: &[u32]) -> Alignment {
+    let mut sorted = positions.to_vec();
+    sorted.sort_unstable();
+    alignment(positions, sorted[(sorted.len() - 1) / 2], &linear_cost)
+}
+
+pub fn incrementing_cost(current: u32, target: u32) -> u64 {
+    let dist = u64::from(current.abs_diff(target));
+    dist * (dist + 1) / 2
+}
+
+/// The triangular cost is half the squared distance plus half the linear
+/// distance, so its minimum lies within one of the mean.
+fn mean_alignment(positions: &[u32]) -> Alignment {
+    let sum: u64 = positions.iter().map(|x| u64::from(*x)).sum();
+    let mean = u32::try_from(sum / positions.len() as u64).unwrap();
+    cheapest(
+        positions,
+        mean.saturating_sub(1)..=mean + 1,
+        &incrementing_cost,
+    )
+}
+
+pub fn part1(input: &str) -> Answer {
+    median_alignment(&parse_input(input)).cost.into()
+}
+
+pub fn part1_convex(input: &str) -> Answer {
+    convex_alignment(&parse_input(input), &linear_cost)
+        .cost
+        .into()
+}
+
+pub fn part1_brute_force(input: &str) -> Answer {
+    brute_force_alignment(&parse_input(input), &linear_cost)
+        .cost
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    mean_alignment(&parse_input(input)).cost.into()
+}
+
+pub fn part2_convex(input: &str) -> Answer {
+    convex_alignment(&parse_input(input), &incrementing_cost)
+        .cost
+        .into()
+}
+
+pub fn part2_brute_force(input: &str) -> Answer {
+    brute_force_alignment(&parse_input(input), &incrementing_cost)
+        .cost
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = r"16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "37");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "168");
+    }
+
+    #[test]
+    fn test_targets() {
+        let positions = parse_input(DATA);
+        assert_eq!(
+            median_alignment(&positions),
+            Alignment {
+                target: 2,
+                cost: 37
+            }
+        );
+        assert_eq!(
+            mean_alignment(&positions),
+            Alignment {
+                target: 5,
+                cost: 168
+            }
+        );
+        assert_eq!(
+            convex_alignment(&positions, &incrementing_cost),
+            mean_alignment(&positions)
+        );
+        assert_eq!(
+            convex_alignment(&[0, 100_000], &incrementing_cost),
+            Alignment {
+                target: 50_000,
+                cost: 2 * 50_000 * 50_001 / 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        let input = include_str!("../input/p07");
+        for data in [DATA, input] {
+            assert_eq!(part1_convex(data), part1(data));
+            assert_eq!(part1_brute_force(data), part1(data));
+            assert_eq!(part2_convex(data), part2(data));
+            assert_eq!(part2_brute_force(data), part2(data));
+        }
+    }
+
+    #[test]
+    fn test_custom_convex_cost() {
+        let squared = |current: u32, target: u32| u64::from(current.abs_diff(target)).pow(2);
+        let uphill = |current: u32, target: u32| {
+            if target > current {
+                3 * u64::from(target - current)
+            } else {
+                u64::from(current - target)
+            }
+        };
+        let input = parse_input(include_str!("../input/p07"));
+        for positions in [parse_input(DATA), input, vec![5, 5, 5], vec![0, 1000]] {
+            assert_eq!(
+                convex_alignment(&positions, &squared).cost,
+                brute_force_alignment(&positions, &squared).cost
+            );
+            assert_eq!(
+                convex_alignment(&positions, &uphill),
+                brute_force_alignment(&positions, &uphill)
+            );
+        }
+    }
+}
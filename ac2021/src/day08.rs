@@ -0,0 +1,207 @@
+use lib::csp::Csp;
+use lib::Answer;
+
+fn parse_input(input: &str) -> Vec<(Vec<&str>, Vec<&str>)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut split = line.split(" | ");
+            (
+                split.next().unwrap().split(' ').collect(),
+                split.next().unwrap().split(' ').collect(),
+            )
+        })
+        .collect()
+}
+
+/// Which segments each digit lights, one bit per segment. Wires are named
+/// from `a` upwards, one per segment.
+pub struct Layout {
+    pub segments: u8,
+    pub digits: [u32; 10],
+}
+
+pub const fn mask(segments: &[u8]) -> u32 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < segments.len() {
+        mask |= 1 << segments[i];
+        i += 1;
+    }
+    mask
+}
+
+/// Segments run clockwise from the top, then the middle bar.
+pub const SEVEN_SEGMENT: Layout = Layout {
+    segments: 7,
+    digits: [
+        mask(&[0, 1, 2, 3, 4, 5]),
+        mask(&[1, 2]),
+        mask(&[0, 1, 3, 4, 6]),
+        mask(&[0, 1, 2, 3, 6]),
+        mask(&[1, 2, 5, 6]),
+        mask(&[0, 2, 3, 5, 6]),
+        mask(&[0, 2, 3, 4, 5, 6]),
+        mask(&[0, 1, 2]),
+        mask(&[0, 1, 2, 3, 4, 5, 6]),
+        mask(&[0, 1, 2, 3, 5, 6]),
+    ],
+};
+
+/// The outer ring clockwise from the top, the two halves of the middle bar,
+/// then the inner diagonals and verticals clockwise from the top left.
+pub const FOURTEEN_SEGMENT: Layout = Layout {
+    segments: 14,
+    digits: [
+        mask(&[0, 1, 2, 3, 4, 5, 10, 11]),
+        mask(&[1, 2, 10]),
+        mask(&[0, 1, 3, 4, 6, 7]),
+        mask(&[0, 1, 2, 3, 7]),
+        mask(&[1, 2, 5, 6, 7]),
+        mask(&[0, 3, 5, 6, 13]),
+        mask(&[0, 2, 3, 4, 5, 6, 7]),
+        mask(&[0, 10, 12]),
+        mask(&[0, 1, 2, 3, 4, 5, 6, 7]),
+        mask(&[0, 1, 2, 3, 5, 6, 7]),
+    ],
+};
+
+impl Layout {
+    fn digit(&self, lit: u32) -> Option<u32> {
+        (0..10).find(|digit| self.digits[*digit as usize] == lit)
+    }
+
+    /// Pattern lengths that only one digit can have.
+    fn unique_lengths(&self) -> Vec<usize> {
+        let lengths = self.digits.map(|digit| digit.count_ones() as usize);
+        lengths
+            .iter()
+            .filter(|length| lengths.iter().filter(|other| other == length).count() == 1)
+            .copied()
+            .collect()
+    }
+
+    /// Finds which segment each wire drives: every pattern seen must light
+    /// a subset of some digit with as many segments as the pattern has wires.
+    pub fn wiring(&self, patterns: &[&str]) -> Option<Vec<u8>> {
+        let mut csp = Csp::new();
+        let wires: Vec<usize> = (0..self.segments)
+            .map(|_| csp.variable(0..self.segments))
+            .collect();
+        csp.all_different(wires.clone());
+        for pattern in patterns {
+            let scope = pattern
+                .bytes()
+                .map(|wire| wires[usize::from(wire - b'a')])
+                .collect();
+            let length = pattern.len() as u32;
+            csp.constrain(scope, move |segments| {
+                let lit = segments.iter().flatten().fold(0, |lit, s| lit | 1 << s);
+                self.digits
+                    .iter()
+                    .any(|digit| digit.count_ones() == length && digit & lit == lit)
+            });
+        }
+        csp.solve()
+    }
+
+    pub fn decode(&self, input: &[&str], output: &[&str]) -> u32 {
+        let patterns: Vec<&str> = input.iter().chain(output).copied().collect();
+        let wiring = self.wiring(&patterns).expect("no wiring fits");
+        output.iter().fold(0, |total, pattern| {
+            let lit = pattern
+                .bytes()
+                .fold(0, |lit, wire| lit | 1 << wiring[usize::from(wire - b'a')]);
+            total * 10 + self.digit(lit).expect("not a digit")
+        })
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    let lengths = SEVEN_SEGMENT.unique_lengths();
+    parse_input(input)
+        .iter()
+        .flat_map(|(_, output)| output)
+        .filter(|pattern| lengths.contains(&pattern.len()))
+        .count()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let data = parse_input(input);
+    data.iter()
+        .map(|(input, output)| SEVEN_SEGMENT.decode(input, output))
+        .sum::<u32>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use lib::generate::Rng;
+
+    use super::*;
+
+    const DATA1: &str = r"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+    const DATA2: &str =
+        r"acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA2), "0");
+        assert_eq!(part1(DATA1), "26");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA2), "5353");
+        assert_eq!(part2(DATA1), "61229");
+    }
+
+    #[test]
+    fn test_unique_lengths() {
+        assert_eq!(SEVEN_SEGMENT.unique_lengths(), [2, 4, 3, 7]);
+    }
+
+    /// Scrambles the wires of `layout` and shows all ten digits, followed by
+    /// `shown`.
+    fn scramble(layout: &Layout, rng: &mut Rng, shown: &[usize]) -> (Vec<String>, Vec<String>) {
+        let mut wiring: Vec<u8> = (0..layout.segments).collect();
+        rng.shuffle(&mut wiring);
+        let pattern = |digit: &u32| -> String {
+            (0..layout.segments)
+                .filter(|wire| digit >> wiring[usize::from(*wire)] & 1 == 1)
+                .map(|wire| char::from(b'a' + wire))
+                .collect()
+        };
+        let mut digits = layout.digits.to_vec();
+        rng.shuffle(&mut digits);
+        (
+            digits.iter().map(pattern).collect(),
+            shown.iter().map(|d| pattern(&layout.digits[*d])).collect(),
+        )
+    }
+
+    #[test]
+    fn test_other_layouts() {
+        let mut rng = Rng::new(8);
+        for layout in [&SEVEN_SEGMENT, &FOURTEEN_SEGMENT] {
+            for _ in 0..20 {
+                let shown: Vec<usize> = (0..4).map(|_| rng.below(10)).collect();
+                let (input, output) = scramble(layout, &mut rng, &shown);
+                let input: Vec<&str> = input.iter().map(String::as_str).collect();
+                let output: Vec<&str> = output.iter().map(String::as_str).collect();
+                let expected = shown.iter().fold(0, |total, d| total * 10 + *d as u32);
+                assert_eq!(layout.decode(&input, &output), expected);
+            }
+        }
+    }
+}
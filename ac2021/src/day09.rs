@@ -1,5 +1,7 @@
 use std::collections::{HashSet, VecDeque};
 
+use lib::Answer;
+
 fn parse_heightmap(input: &str) -> Vec<Vec<u32>> {
     input
         .lines()
@@ -45,13 +47,13 @@ fn list_low_points(map: &[Vec<u32>]) -> Vec<(usize, usize)> {
         .collect()
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let map = parse_heightmap(input);
     list_low_points(&map)
         .iter()
         .map(|(x, y)| map[*x][*y] + 1)
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 fn get_basin_size(low_point: &(usize, usize), map: &[Vec<u32>]) -> usize {
@@ -78,7 +80,7 @@ fn get_basin_size(low_point: &(usize, usize), map: &[Vec<u32>]) -> usize {
     checked.len()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let map = parse_heightmap(input);
     let low_points = list_low_points(&map);
     let mut basins: Vec<usize> = low_points
@@ -87,7 +89,7 @@ pub fn part2(input: &str) -> String {
         .collect();
     basins.sort_unstable();
     basins.reverse();
-    basins.into_iter().take(3).product::<usize>().to_string()
+    basins.into_iter().take(3).product::<usize>().into()
 }
 
 #[cfg(test)]
@@ -0,0 +1,292 @@
+use lib::num::{BigUint, Natural};
+use lib::Answer;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Delimiter {
+    pub open: char,
+    pub close: char,
+    pub corruption_score: u64,
+    pub completion_score: u64,
+}
+
+/// Matches delimiters on a line; any other character is left alone. A
+/// delimiter may open and close with the same character, in which case it
+/// closes whenever it is the innermost one open.
+#[derive(Debug, Clone, Copy)]
+pub struct Brackets<'a> {
+    pub delimiters: &'a [Delimiter],
+    /// Each closing character of a completion multiplies the score so far
+    /// by this before adding its own.
+    pub completion_base: u64,
+}
+
+pub const CHUNKS: Brackets = Brackets {
+    delimiters: &[
+        Delimiter {
+            open: '(',
+            close: ')',
+            corruption_score: 3,
+            completion_score: 1,
+        },
+        Delimiter {
+            open: '[',
+            close: ']',
+            corruption_score: 57,
+            completion_score: 2,
+        },
+        Delimiter {
+            open: '{',
+            close: '}',
+            corruption_score: 1197,
+            completion_score: 3,
+        },
+        Delimiter {
+            open: '<',
+            close: '>',
+            corruption_score: 25137,
+            completion_score: 4,
+        },
+    ],
+    completion_base: 5,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The closing characters that would complete the line, in order.
+    Incomplete(String),
+    /// The first closing character that does not match, by character
+    /// position, with the one that was due instead (if any was open).
+    Corrupted {
+        position: usize,
+        found: char,
+        expected: Option<char>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub status: Status,
+    pub max_depth: usize,
+    /// How many delimiters were opened and properly closed.
+    pub closed: usize,
+}
+
+impl Brackets<'_> {
+    fn opening(&self, c: char) -> Option<&Delimiter> {
+        self.delimiters.iter().find(|delimiter| delimiter.open == c)
+    }
+
+    fn closing(&self, c: char) -> Option<&Delimiter> {
+        self.delimiters
+            .iter()
+            .find(|delimiter| delimiter.close == c)
+    }
+
+    /// Checks a line, stopping at the first corruption.
+    pub fn check(&self, line: &str) -> Report {
+        let mut stack: Vec<&Delimiter> = vec![];
+        let mut max_depth = 0;
+        let mut closed = 0;
+        for (position, c) in line.chars().enumerate() {
+            let innermost = stack.last().map(|delimiter| delimiter.close);
+            if innermost == Some(c) {
+                stack.pop();
+                closed += 1;
+            } else if let Some(delimiter) = self.opening(c) {
+                stack.push(delimiter);
+                max_depth = max_depth.max(stack.len());
+            } else if self.closing(c).is_some() {
+                return Report {
+                    status: Status::Corrupted {
+                        position,
+                        found: c,
+                        expected: innermost,
+                    },
+                    max_depth,
+                    closed,
+                };
+            }
+        }
+        let status = if stack.is_empty() {
+            Status::Ok
+        } else {
+            Status::Incomplete(
+                stack
+                    .iter()
+                    .rev()
+                    .map(|delimiter| delimiter.close)
+                    .collect(),
+            )
+        };
+        Report {
+            status,
+            max_depth,
+            closed,
+        }
+    }
+
+    pub fn corruption_score(&self, status: &Status) -> Option<u64> {
+        match status {
+            Status::Corrupted { found, .. } => self
+                .closing(*found)
+                .map(|delimiter| delimiter.corruption_score),
+            _ => None,
+        }
+    }
+
+    /// Scores grow by a factor of `completion_base` per closing character,
+    /// so long completions outgrow any fixed-width integer.
+    pub fn completion_score(&self, status: &Status) -> Option<BigUint> {
+        let base = BigUint::from(self.completion_base);
+        match status {
+            Status::Incomplete(completion) => completion
+                .chars()
+                .map(|c| self.closing(c).map(|delimiter| delimiter.completion_score))
+                .try_fold(BigUint::zero(), |total, score| {
+                    let total = total.mul(&base).expect("big integers do not overflow");
+                    Some(
+                        total
+                            .add(&BigUint::from(score?))
+                            .expect("big integers do not overflow"),
+                    )
+                }),
+            _ => None,
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    input
+        .lines()
+        .filter_map(|line| CHUNKS.corruption_score(&CHUNKS.check(line).status))
+        .sum::<u64>()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut scored = input
+        .lines()
+        .filter_map(|line| CHUNKS.completion_score(&CHUNKS.check(line).status))
+        .collect::<Vec<BigUint>>();
+    scored.sort_unstable();
+    scored
+        .get(scored.len() / 2)
+        .cloned()
+        .expect("no incomplete lines")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = r"[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "26397");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "288957");
+    }
+
+    #[test]
+    fn test_reports() {
+        let mut lines = DATA.lines();
+        assert_eq!(
+            CHUNKS.check(lines.next().unwrap()),
+            Report {
+                status: Status::Incomplete("}}]])})]".to_string()),
+                max_depth: 10,
+                closed: 8,
+            }
+        );
+        assert_eq!(
+            CHUNKS.check(lines.nth(1).unwrap()).status,
+            Status::Corrupted {
+                position: 12,
+                found: '}',
+                expected: Some(']'),
+            }
+        );
+        assert_eq!(
+            CHUNKS.check("<>)").status,
+            Status::Corrupted {
+                position: 2,
+                found: ')',
+                expected: None,
+            }
+        );
+        assert_eq!(CHUNKS.check("[<>({}){}[([])<>]]").status, Status::Ok);
+    }
+
+    #[test]
+    fn test_long_completion() {
+        let score = |line: &str| CHUNKS.completion_score(&CHUNKS.check(line).status);
+        assert_eq!(
+            score(&"<".repeat(27)),
+            Some(BigUint::from(5u64.pow(27) - 1))
+        );
+        assert_eq!(
+            score(&"<".repeat(30)),
+            Some(BigUint::from(5u128.pow(30) - 1))
+        );
+        let long = "<".repeat(60);
+        let score = score(&long).unwrap();
+        assert_eq!(score.to_string().len(), 42);
+        let input = format!("{long}\n{long}\n<\n");
+        assert_eq!(part2(&input), Answer::from(score));
+    }
+
+    #[test]
+    fn test_other_delimiters() {
+        let delimiters = [
+            Delimiter {
+                open: '"',
+                close: '"',
+                corruption_score: 1,
+                completion_score: 1,
+            },
+            Delimiter {
+                open: '«',
+                close: '»',
+                corruption_score: 10,
+                completion_score: 2,
+            },
+        ];
+        let quotes = Brackets {
+            delimiters: &delimiters,
+            completion_base: 10,
+        };
+        let report = quotes.check(r#"say «"hi" and «"bye»"#);
+        assert_eq!(
+            report.status,
+            Status::Corrupted {
+                position: 19,
+                found: '»',
+                expected: Some('"'),
+            }
+        );
+        assert_eq!(quotes.corruption_score(&report.status), Some(10));
+        let report = quotes.check(r#"«a "b «c"#);
+        assert_eq!(report.status, Status::Incomplete("»\"»".to_string()));
+        assert_eq!(report.max_depth, 3);
+        assert_eq!(
+            quotes.completion_score(&report.status),
+            Some(BigUint::from(212u64))
+        );
+    }
+}
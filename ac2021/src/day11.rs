@@ -0,0 +1,94 @@
+use lib::automaton::{Automaton, Cascade, Edges};
+use lib::grid::{Grid, ADJACENT};
+use lib::Answer;
+
+/// Every octopus gains a level each step, and one more for each neighbour
+/// that flashes; past nine it flashes and drops back to zero.
+struct Octopuses;
+
+impl Cascade<u8> for Octopuses {
+    fn start(&self, energy: &mut u8) -> bool {
+        self.excite(energy)
+    }
+
+    fn excite(&self, energy: &mut u8) -> bool {
+        *energy += 1;
+        *energy > 9
+    }
+
+    fn settle(&self, energy: &mut u8) {
+        *energy = 0;
+    }
+}
+
+fn parse(input: &str) -> Automaton<'static, u8> {
+    let grid = Grid::parse(input, |c| {
+        c.to_digit(10).expect("energy levels are digits") as u8
+    });
+    Automaton::new(grid, &ADJACENT, Edges::Bounded)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut octopuses = parse(input);
+    (0..100)
+        .map(|_| octopuses.cascade(&Octopuses).fired)
+        .sum::<usize>()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut octopuses = parse(input);
+    let all = octopuses.grid.width() * octopuses.grid.height();
+    octopuses
+        .run_until(
+            |octopuses| octopuses.cascade(&Octopuses),
+            |_, stats| stats.fired == all,
+            None,
+        )
+        .expect("an unlimited run only stops once done")
+        .generation
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = r"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "1656");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "195");
+    }
+
+    #[test]
+    fn test_steps() {
+        let mut octopuses = parse(DATA);
+        let fired: Vec<usize> = (0..3)
+            .map(|_| octopuses.cascade(&Octopuses).fired)
+            .collect();
+        assert_eq!(fired, [0, 35, 45]);
+        let rows: Vec<String> = octopuses
+            .grid
+            .rows()
+            .take(2)
+            .map(|row| row.iter().map(u8::to_string).collect())
+            .collect();
+        assert_eq!(rows, ["0050900866", "8500800575"]);
+    }
+}
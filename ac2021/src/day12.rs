@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_while1},
@@ -134,18 +135,18 @@ fn list_paths<F: Fn(&[Node]) -> bool + Clone>(
         .collect()
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, data) = parse_network(input).unwrap();
     let map = network_map(&data);
     let paths = list_paths(&map, &[Node::Start], valid_partial_path_1);
-    paths.len().to_string()
+    paths.len().into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, data) = parse_network(input).unwrap();
     let map = network_map(&data);
     let paths = list_paths(&map, &[Node::Start], valid_partial_path_2);
-    paths.len().to_string()
+    paths.len().into()
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use lib::Answer;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, digit1, newline, one_of};
 use nom::combinator::map_res;
@@ -74,32 +75,29 @@ fn execute_fold(dots: &mut Vec<Point>, fold_instruction: &FoldInstruction) {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (mut dots, folds)) = parse_input(input).unwrap();
     execute_fold(&mut dots, folds.first().unwrap());
-    dots.iter().collect::<HashSet<_>>().len().to_string()
+    dots.iter().collect::<HashSet<_>>().len().into()
 }
 
-fn render_grid(dots: &Vec<Point>) -> String {
+fn render_grid(dots: &Vec<Point>) -> Vec<Vec<bool>> {
     let max_x = dots.iter().map(|x| x.0).max().unwrap();
     let max_y = dots.iter().map(|x| x.1).max().unwrap();
 
-    let mut grid = vec![vec![' '; max_x + 1]; max_y + 1];
+    let mut grid = vec![vec![false; max_x + 1]; max_y + 1];
     for dot in dots {
-        grid[dot.1][dot.0] = '#';
+        grid[dot.1][dot.0] = true;
     }
-    grid.iter()
-        .map(|line| line.iter().collect())
-        .collect::<Vec<String>>()
-        .join("\n")
+    grid
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (mut dots, folds)) = parse_input(input).unwrap();
     for fold in folds {
         execute_fold(&mut dots, &fold);
     }
-    format!("\n{}", render_grid(&dots))
+    render_grid(&dots).into()
 }
 
 #[cfg(test)]
@@ -138,4 +136,9 @@ fold along x=5
     fn test_part2() {
         part2(DATA);
     }
+
+    #[test]
+    fn test_part2_letters() {
+        assert_eq!(part2(include_str!("../input/p13")), "FJAHJGAH");
+    }
 }
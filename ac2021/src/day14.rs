@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use lib::num::{BigUint, Natural, Overflow};
+use lib::Answer;
 use nom::bytes::complete::tag;
 use nom::character::complete::{alpha1, newline};
 use nom::multi::{count, separated_list1};
@@ -69,14 +71,14 @@ fn transform_rules2(rules: &HashMap<[char; 2], char>) -> HashMap<[char; 2], Vec<
         .collect()
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (polymer, rules)) = parse_input(input).unwrap();
     let rules = transform_rules(&rules);
     let mut polymer: Vec<char> = polymer.chars().collect();
     for _ in 0..10 {
         polymer = apply_rules(&polymer, &rules);
     }
-    minmax(&frequencies(&polymer)).to_string()
+    minmax(&frequencies(&polymer)).into()
 }
 
 fn transform_rules3(rules: &HashMap<[char; 2], Vec<char>>) -> HashMap<[char; 2], Vec<[char; 2]>> {
@@ -91,48 +93,75 @@ fn transform_rules3(rules: &HashMap<[char; 2], Vec<char>>) -> HashMap<[char; 2],
         .collect()
 }
 
-fn statistical_solve(
+fn statistical_solve<N: Natural>(
     polymer: &[char],
     rules: &HashMap<[char; 2], Vec<[char; 2]>>,
-    count: u8,
-) -> HashMap<char, usize> {
-    let mut set_counts: HashMap<[char; 2], usize> = HashMap::new();
+    count: usize,
+) -> Result<HashMap<char, N>, Overflow> {
+    let mut set_counts: HashMap<[char; 2], N> = HashMap::new();
     for window in polymer.windows(2).map(|iter| [iter[0], iter[1]]) {
-        add_to_map(&mut set_counts, window, 1);
+        add_to_map(&mut set_counts, window, &N::from_u64(1))?;
     }
     for _ in 0..count {
-        let mut new_set_counts: HashMap<[char; 2], usize> =
-            rules.values().flatten().map(|x| (*x, 0)).collect();
+        let mut new_set_counts: HashMap<[char; 2], N> = HashMap::new();
         for (set, set_count) in &set_counts {
             match rules.get(set) {
-                None => add_to_map(&mut new_set_counts, *set, *set_count),
+                None => add_to_map(&mut new_set_counts, *set, set_count)?,
                 Some(sets) => {
                     for set in sets {
-                        add_to_map(&mut new_set_counts, *set, *set_count);
+                        add_to_map(&mut new_set_counts, *set, set_count)?;
                     }
                 }
             }
         }
         set_counts = new_set_counts;
     }
+    // Every element starts exactly one pair, except the last one, which
+    // never changes.
     let mut frequencies = HashMap::new();
-    set_counts
-        .iter()
-        .flat_map(|(set, count)| [(set[0], *count), (set[1], *count)])
-        .for_each(|(c, count)| add_to_map(&mut frequencies, c, count));
-    frequencies
+    for (set, count) in &set_counts {
+        add_to_map(&mut frequencies, set[0], count)?;
+    }
+    add_to_map(&mut frequencies, *polymer.last().unwrap(), &N::from_u64(1))?;
+    Ok(frequencies)
 }
 
-fn add_to_map<T: Hash + Eq>(map: &mut HashMap<T, usize>, index: T, val: usize) {
-    *map.entry(index).or_insert(0) += val;
+fn add_to_map<T: Hash + Eq, N: Natural>(
+    map: &mut HashMap<T, N>,
+    index: T,
+    val: &N,
+) -> Result<(), Overflow> {
+    let entry = map.entry(index).or_insert_with(N::zero);
+    *entry = entry.add(val)?;
+    Ok(())
 }
 
-pub fn part2(input: &str) -> String {
+fn statistical_minmax<N: Natural>(input: &str, count: usize) -> Result<N, Overflow> {
     let (_, (polymer, rules)) = parse_input(input).unwrap();
     let rules = transform_rules3(&transform_rules2(&rules));
     let polymer: Vec<char> = polymer.chars().collect();
-    let frequencies = statistical_solve(&polymer, &rules, 40);
-    (minmax(&frequencies) / 2 + 1).to_string()
+    let frequencies = statistical_solve::<N>(&polymer, &rules, count)?;
+    let max = frequencies.values().max().unwrap();
+    let min = frequencies.values().min().unwrap();
+    max.sub(min)
+}
+
+pub fn part1_statistical(input: &str) -> Answer {
+    statistical_minmax::<u64>(input, 10)
+        .expect("polymer overflowed u64")
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    statistical_minmax::<u64>(input, 40)
+        .expect("polymer overflowed u64")
+        .into()
+}
+
+pub fn part2_bigint(input: &str) -> Answer {
+    statistical_minmax::<BigUint>(input, 40)
+        .expect("bigint cannot overflow")
+        .into()
 }
 
 #[cfg(test)]
@@ -163,8 +192,27 @@ CN -> C
         assert_eq!(part1(DATA), "1588");
     }
 
+    #[test]
+    fn test_part1_variants_agree() {
+        assert_eq!(part1_statistical(DATA), part1(DATA));
+        let input = include_str!("../input/p14");
+        assert_eq!(part1_statistical(input), part1(input));
+    }
+
     #[test]
     fn test_part2() {
         assert_eq!(part2(DATA), "2188189693529");
     }
+
+    #[test]
+    fn test_long_polymer() {
+        assert_eq!(part2_bigint(DATA), part2(DATA));
+        assert_eq!(statistical_minmax::<u64>(DATA, 100), Err(Overflow));
+        assert_eq!(
+            statistical_minmax::<BigUint>(DATA, 100)
+                .unwrap()
+                .to_string(),
+            "2535296262066596202993060773164"
+        );
+    }
 }
@@ -0,0 +1,104 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use lib::grid::{Cells, Grid};
+use lib::Answer;
+
+fn parse_risks(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10).expect("risk levels are digits"))
+}
+
+/// Repeats the cave `times` times in each direction, each repetition one
+/// higher in risk than the tile left of or above it, wrapping 9 back to 1.
+fn tile(cave: &Grid<u32>, times: usize) -> Grid<u32> {
+    let (width, height) = (cave.width(), cave.height());
+    Grid::from_rows(
+        (0..height * times)
+            .map(|y| {
+                (0..width * times)
+                    .map(|x| {
+                        let risk = cave[(coord(x % width), coord(y % height))];
+                        let shift = u32::try_from(x / width + y / height).unwrap();
+                        (risk + shift - 1) % 9 + 1
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+fn coord(value: usize) -> i64 {
+    i64::try_from(value).unwrap()
+}
+
+/// Dijkstra from the top-left to the bottom-right corner; the risk of the
+/// starting position is never entered, so it is not counted.
+fn lowest_risk(cave: &Grid<u32>) -> u32 {
+    let target = (coord(cave.width()) - 1, coord(cave.height()) - 1);
+    let mut best = Grid::new(cave.width(), cave.height(), u32::MAX);
+    let mut queue = BinaryHeap::from([Reverse((0, (0, 0)))]);
+    best[(0, 0)] = 0;
+    while let Some(Reverse((risk, at))) = queue.pop() {
+        if at == target {
+            return risk;
+        }
+        if risk > best[at] {
+            continue;
+        }
+        for (next, cost) in cave.orthogonal(at) {
+            let total = risk + cost;
+            if total < best[next] {
+                best[next] = total;
+                queue.push(Reverse((total, next)));
+            }
+        }
+    }
+    unreachable!("the bottom-right corner is always reachable")
+}
+
+pub fn part1(input: &str) -> Answer {
+    lowest_risk(&parse_risks(input)).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    lowest_risk(&tile(&parse_risks(input), 5)).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA: &str = r"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "40");
+    }
+
+    #[test]
+    fn test_tile() {
+        let cave = tile(&parse_risks(DATA), 5);
+        assert_eq!((cave.width(), cave.height()), (50, 50));
+        assert_eq!(cave[(10, 0)], 2);
+        assert_eq!(cave[(49, 49)], 9);
+        assert_eq!(
+            cave.rows().last().unwrap()[40..],
+            [1, 2, 9, 9, 8, 3, 3, 4, 7, 9]
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "315");
+    }
+}
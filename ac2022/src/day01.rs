@@ -1,9 +1,12 @@
-pub fn part1(input: &str) -> String {
+use lib::Answer;
+
+pub fn part1(input: &str) -> Answer {
     process_input(input)
         .iter()
         .max()
+        .copied()
         .expect("empty input")
-        .to_string()
+        .into()
 }
 
 fn process_input(input: &str) -> Vec<u32> {
@@ -18,10 +21,10 @@ fn process_input(input: &str) -> Vec<u32> {
         .collect()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let mut sums: Vec<u32> = process_input(input);
     sums.sort_unstable();
-    sums.iter().rev().take(3).sum::<u32>().to_string()
+    sums.iter().rev().take(3).sum::<u32>().into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use lib::Answer;
+
 type Recommendation = (Move, Move);
 type Recommendation2 = (Move, Outcome);
 
@@ -15,21 +17,21 @@ enum Outcome {
     Loss = 0,
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let data = process_input(input);
     data.iter()
         .map(|recommend| get_move_score(*recommend) + get_outcome(*recommend) as u32)
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let data = process_input_2(input);
     data.iter()
         .map(|x| translate(*x))
         .map(|recommend| -> u32 { get_move_score(recommend) + get_outcome(recommend) as u32 })
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 const fn translate(rec: Recommendation2) -> Recommendation {
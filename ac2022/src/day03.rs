@@ -2,6 +2,8 @@ use std::collections::hash_map::RandomState;
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use lib::Answer;
+
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 struct Item {
     letter: char,
@@ -62,12 +64,12 @@ fn process_input(input: &str) -> Vec<Rucksack> {
         .collect()
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let data = process_input(input);
     data.iter()
         .map(|rucksack| rucksack.get_duplicate().score)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 fn find_common_item(sack1: &Rucksack, sack2: &Rucksack, sack3: &Rucksack) -> Item {
@@ -83,14 +85,14 @@ fn find_common_item(sack1: &Rucksack, sack2: &Rucksack, sack3: &Rucksack) -> Ite
         .unwrap()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let data = process_input(input);
     data.iter()
         .enumerate()
         .step_by(3)
         .map(|(i, rucksack)| find_common_item(rucksack, &data[i + 1], &data[i + 2]).score)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
-pub fn part1(input: &str) -> String {
+use lib::Answer;
+
+pub fn part1(input: &str) -> Answer {
     input
         .lines()
         .filter(|x| !x.is_empty())
@@ -24,10 +26,10 @@ pub fn part1(input: &str) -> String {
                 || (elf2_low <= elf1_low && elf2_high >= elf1_high)
         })
         .count()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     input
         .lines()
         .filter(|x| !x.is_empty())
@@ -55,7 +57,7 @@ pub fn part2(input: &str) -> String {
                 || (elf1_low <= elf2_high && elf1_high >= elf2_high)
         })
         .count()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -0,0 +1,263 @@
+use std::str::FromStr;
+
+use lib::Answer;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take, take_while_m_n};
+use nom::character::complete::digit1;
+use nom::combinator::{map, map_res};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+#[derive(Copy, Clone, Debug)]
+enum Element {
+    Present(char),
+    Empty,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub amt: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Move {
+    fn execute<T>(&self, stack: &mut [Vec<T>]) {
+        for _ in 0..self.amt {
+            let elem = stack[self.from - 1].pop().unwrap();
+            stack[self.to - 1].push(elem);
+        }
+    }
+
+    fn execute_9001<T>(&self, stack: &mut [Vec<T>]) {
+        let mut intermediate = vec![];
+        for _ in 0..self.amt {
+            intermediate.push(stack[self.from - 1].pop().unwrap());
+        }
+        for _ in 0..self.amt {
+            stack[self.to - 1].push(intermediate.pop().unwrap());
+        }
+    }
+}
+
+fn present_element(input: &str) -> IResult<&str, Element> {
+    let (input, _) = tag("[")(input)?;
+    let (input, c) = map_res(take(1usize), char::from_str)(input)?;
+    let (input, _) = tag("]")(input)?;
+    Ok((input, Element::Present(c)))
+}
+
+fn empty_element(input: &str) -> IResult<&str, Element> {
+    map(take_while_m_n(3, 3, |x| x == ' '), |_| Element::Empty)(input)
+}
+
+fn element(input: &str) -> IResult<&str, Element> {
+    alt((present_element, empty_element))(input)
+}
+
+fn stack_row(input: &str) -> IResult<&str, Vec<Element>> {
+    separated_list1(tag(" "), element)(input)
+}
+
+fn stack_index(input: &str) -> IResult<&str, u8> {
+    let (input, _) = tag(" ")(input)?;
+    let (input, i) = map_res(take(1usize), u8::from_str)(input)?;
+    let (input, _) = tag(" ")(input)?;
+    Ok((input, i))
+}
+
+fn index_row(input: &str) -> IResult<&str, Vec<u8>> {
+    separated_list1(tag(" "), stack_index)(input)
+}
+
+fn transpose<T: Copy>(original: &[Vec<T>]) -> Vec<Vec<T>> {
+    (0..original[0].len())
+        .map(|index2| {
+            (0..original.len())
+                .map(|index1| original[index1][index2])
+                .collect()
+        })
+        .collect()
+}
+
+pub fn stack(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    let (input, mut stack) = separated_list1(tag("\n"), stack_row)(input)?;
+    let (input, _) = tag("\n")(input)?;
+    let (input, _) = index_row(input)?;
+    stack.reverse();
+    let stack = transpose(&stack);
+    let stack = stack
+        .iter()
+        .map(|line| {
+            line.iter()
+                .filter_map(|elem| match *elem {
+                    Element::Present(x) => Some(x),
+                    Element::Empty => None,
+                })
+                .collect()
+        })
+        .collect();
+    Ok((input, stack))
+}
+
+fn instruction(input: &str) -> IResult<&str, Move> {
+    let (input, _) = tag("move ")(input)?;
+    let (input, amt) = map_res(digit1, str::parse)(input)?;
+    let (input, _) = tag(" from ")(input)?;
+    let (input, from) = map_res(digit1, str::parse)(input)?;
+    let (input, _) = tag(" to ")(input)?;
+    let (input, to) = map_res(digit1, str::parse)(input)?;
+    Ok((input, Move { amt, from, to }))
+}
+
+fn instructions(input: &str) -> IResult<&str, Vec<Move>> {
+    separated_list1(tag("\n"), instruction)(input)
+}
+
+pub fn parse_all(input: &str) -> IResult<&str, (Vec<Vec<char>>, Vec<Move>)> {
+    let (input, stack) = stack(input)?;
+    let (input, _) = tag("\n\n")(input)?;
+    let (input, orders) = instructions(input)?;
+    Ok((input, (stack, orders)))
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (_, (mut stack, orders)) = parse_all(input).unwrap();
+    orders.iter().for_each(|order| order.execute(&mut stack));
+    stack
+        .iter()
+        .map(|line| line.last().unwrap())
+        .collect::<String>()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (_, (mut stack, orders)) = parse_all(input).unwrap();
+    orders
+        .iter()
+        .for_each(|order| order.execute_9001(&mut stack));
+    stack
+        .iter()
+        .map(|line| line.last().unwrap())
+        .collect::<String>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use lib::prop::{chars, for_all, ints, vecs};
+
+    const DATA: &str = "    [D]\x20\x20\x20\x20
+[N] [C]\x20\x20\x20\x20
+[Z] [M] [P]
+ 1   2   3\x20
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA), "CMZ");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA), "MCD");
+    }
+
+    fn render_stacks(stacks: &[Vec<char>]) -> String {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        let mut rows: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| {
+                        stack
+                            .get(level)
+                            .map_or("   ".to_string(), |c| format!("[{c}]"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        rows.push(
+            (1..=stacks.len())
+                .map(|i| format!(" {i} "))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        rows.join("\n")
+    }
+
+    fn render(stacks: &[Vec<char>], moves: &[Move]) -> String {
+        let moves: Vec<String> = moves
+            .iter()
+            .map(|Move { amt, from, to }| format!("move {amt} from {from} to {to}"))
+            .collect();
+        format!("{}\n\n{}\n", render_stacks(stacks), moves.join("\n"))
+    }
+
+    /// Turns raw numbers into moves that only take crates that are there.
+    fn valid_moves(stacks: &[Vec<char>], raw: &[(u64, u64, u64)]) -> Vec<Move> {
+        let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+        let mut moves = vec![];
+        for &(from, to, amt) in raw {
+            let sources: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+            if sources.is_empty() {
+                break;
+            }
+            let from = sources[from as usize % sources.len()];
+            let to = to as usize % heights.len();
+            let amt = 1 + amt as usize % heights[from];
+            heights[from] -= amt;
+            heights[to] += amt;
+            moves.push(Move {
+                amt: amt as u32,
+                from: from + 1,
+                to: to + 1,
+            });
+        }
+        moves
+    }
+
+    fn sorted_crates(stacks: &[Vec<char>]) -> Vec<char> {
+        let mut crates: Vec<char> = stacks.iter().flatten().copied().collect();
+        crates.sort_unstable();
+        crates
+    }
+
+    #[test]
+    fn test_stack_round_trip() {
+        let crates = vecs(chars("ABCDEFGHIJKLMNOPQRSTUVWXYZ"), 0..=8);
+        for_all(&vecs(crates, 1..=9), |stacks| {
+            let drawing = render_stacks(stacks);
+            assert_eq!(stack(&drawing), Ok(("", stacks.clone())), "\n{drawing}");
+        });
+    }
+
+    #[test]
+    fn test_moves_keep_crates() {
+        let crates = vecs(chars("ABCDEFGHIJKLMNOPQRSTUVWXYZ"), 1..=8);
+        let raw = vecs((ints(0..=8), ints(0..=8), ints(0..=8)), 1..=20);
+        for_all(&(vecs(crates, 1..=9), raw), |(stacks, raw)| {
+            let moves = valid_moves(stacks, raw);
+            let input = render(stacks, &moves);
+            let (_, (parsed, orders)) = parse_all(&input).unwrap();
+            assert_eq!((&parsed, &orders), (stacks, &moves));
+            let (mut single, mut multiple) = (parsed.clone(), parsed);
+            for order in &orders {
+                order.execute(&mut single);
+                order.execute_9001(&mut multiple);
+            }
+            assert_eq!(sorted_crates(&single), sorted_crates(stacks));
+            assert_eq!(sorted_crates(&multiple), sorted_crates(stacks));
+            let heights = |stacks: &[Vec<char>]| stacks.iter().map(Vec::len).collect::<Vec<_>>();
+            assert_eq!(heights(&single), heights(&multiple));
+        });
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use lib::Answer;
+
 fn find_unique_sequence(input: &str, window_size: usize) -> usize {
     input
         .as_bytes()
@@ -11,12 +13,12 @@ fn find_unique_sequence(input: &str, window_size: usize) -> usize {
         + window_size
 }
 
-pub fn part1(input: &str) -> String {
-    find_unique_sequence(input, 4).to_string()
+pub fn part1(input: &str) -> Answer {
+    find_unique_sequence(input, 4).into()
 }
 
-pub fn part2(input: &str) -> String {
-    find_unique_sequence(input, 14).to_string()
+pub fn part2(input: &str) -> Answer {
+    find_unique_sequence(input, 14).into()
 }
 
 #[cfg(test)]
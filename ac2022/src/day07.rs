@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while1};
 use nom::character::complete::{alpha1, char, digit1, newline};
@@ -146,7 +147,7 @@ fn get_file_tree(commands: &[Command]) -> FsObject {
     tree
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, data) = commands(input).unwrap();
     let tree = get_file_tree(&data);
 
@@ -155,10 +156,10 @@ pub fn part1(input: &str) -> String {
         .map(|x| x.get_size())
         .filter(|x| *x <= 100_000)
         .sum::<u64>()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, data) = commands(input).unwrap();
     let tree = get_file_tree(&data);
     let must_clean = 30_000_000 - (70_000_000 - tree.get_size());
@@ -169,7 +170,7 @@ pub fn part2(input: &str) -> String {
         .filter(|x| *x >= must_clean)
         .min()
         .unwrap()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,6 @@
+use lib::grid::BitGrid;
+use lib::Answer;
+
 fn parse(input: &str) -> Vec<Vec<u8>> {
     input
         .lines()
@@ -9,37 +12,30 @@ fn parse(input: &str) -> Vec<Vec<u8>> {
         .collect()
 }
 
-fn mark_visible(trees: &[Vec<u8>]) -> Vec<Vec<bool>> {
-    let mut visible = vec![vec![false; trees[0].len()]; trees.len()];
-    visible
-        .first_mut()
-        .unwrap()
-        .iter_mut()
-        .for_each(|x| *x = true);
-    visible
-        .last_mut()
-        .unwrap()
-        .iter_mut()
-        .for_each(|x| *x = true);
-    visible
-        .iter_mut()
-        .for_each(|line| *line.first_mut().unwrap() = true);
-    visible
-        .iter_mut()
-        .for_each(|line| *line.last_mut().unwrap() = true);
+fn mark_visible(trees: &[Vec<u8>]) -> BitGrid {
+    let (width, height) = (trees[0].len(), trees.len());
+    let mut visible = BitGrid::new(width, height);
+    for j in 0..width {
+        visible.set(j, 0);
+        visible.set(j, height - 1);
+    }
+    for i in 0..height {
+        visible.set(0, i);
+        visible.set(width - 1, i);
+    }
     for (i, line) in trees.iter().enumerate().skip(1) {
         let mut prev_max = line.first().unwrap();
         for (j, height) in line.iter().enumerate().skip(1) {
             if height > prev_max {
                 prev_max = height;
-                visible[i][j] = true;
+                visible.set(j, i);
             }
         }
         let mut prev_max = line.last().unwrap();
         for (j, height) in line.iter().enumerate().rev().skip(1) {
             if height > prev_max {
                 prev_max = height;
-                visible[i][j] = true;
+                visible.set(j, i);
             }
         }
     }
@@ -48,27 +44,23 @@ fn mark_visible(trees: &[Vec<u8>]) -> Vec<Vec<bool>> {
         for (i, height) in trees.iter().map(|line| line[j]).enumerate().skip(1) {
             if height > prev_max {
                 prev_max = height;
-                visible[i][j] = true;
+                visible.set(j, i);
             }
         }
         let mut prev_max = trees.last().unwrap()[j];
         for (i, height) in trees.iter().map(|line| line[j]).enumerate().rev().skip(1) {
             if height > prev_max {
                 prev_max = height;
-                visible[i][j] = true;
+                visible.set(j, i);
             }
         }
     }
     visible
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let data = parse(input);
-    mark_visible(&data)
-        .iter()
-        .map(|line| line.iter().filter(|x| **x).count())
-        .sum::<usize>()
-        .to_string()
+    mark_visible(&data).count().into()
 }
 
 fn get_view_from(trees: &[Vec<u8>], x: usize, y: usize) -> usize {
@@ -101,7 +93,7 @@ fn get_view_from(trees: &[Vec<u8>], x: usize, y: usize) -> usize {
     result
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let data = parse(input);
     data.iter()
         .enumerate()
@@ -118,7 +110,7 @@ pub fn part2(input: &str) -> String {
         })
         .max()
         .unwrap()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
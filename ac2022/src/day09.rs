@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use lib::viz::{Frame, Simulation};
+use lib::Answer;
+use nom::character::complete::{alpha1, char, digit1, newline};
+use nom::combinator::map_res;
+use nom::multi::separated_list1;
+use nom::IResult;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "U" => Self::Up,
+            "D" => Self::Down,
+            "L" => Self::Left,
+            "R" => Self::Right,
+            _ => panic!("Invalid character {s}"),
+        })
+    }
+}
+
+fn direction(input: &str) -> IResult<&str, Direction> {
+    map_res(alpha1, str::parse)(input)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Motion {
+    direction: Direction,
+    amount: u8,
+}
+
+fn motion(input: &str) -> IResult<&str, Motion> {
+    let (input, direction) = direction(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, amount) = map_res(digit1, str::parse)(input)?;
+    Ok((input, Motion { direction, amount }))
+}
+
+fn motions(input: &str) -> IResult<&str, Vec<Motion>> {
+    separated_list1(newline, motion)(input)
+}
+
+type Point = (i32, i32);
+
+struct Path {
+    knots: Vec<Point>,
+    tail_visited: HashSet<Point>,
+}
+
+impl Path {
+    fn new(knots: usize) -> Self {
+        Self {
+            knots: vec![(0, 0); knots],
+            tail_visited: HashSet::from_iter(vec![(0, 0)]),
+        }
+    }
+
+    fn step(&mut self, direction: Direction) {
+        let change = match direction {
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        self.knots[0].0 += change.0;
+        self.knots[0].1 += change.1;
+        for i in 1..self.knots.len() {
+            self.move_knot(i);
+        }
+        self.tail_visited.insert(*self.knots.last().unwrap());
+    }
+
+    fn move_knot(&mut self, index: usize) {
+        if (self.knots[index - 1].0.abs_diff(self.knots[index].0) == 2)
+            && (self.knots[index - 1].1.abs_diff(self.knots[index].1) == 2)
+        {
+            self.knots[index].0 = (self.knots[index].0 + self.knots[index - 1].0) / 2;
+            self.knots[index].1 = (self.knots[index].1 + self.knots[index - 1].1) / 2;
+        } else if self.knots[index - 1].0.abs_diff(self.knots[index].0) == 2 {
+            self.knots[index].0 = (self.knots[index].0 + self.knots[index - 1].0) / 2;
+            self.knots[index].1 = self.knots[index - 1].1;
+        } else if self.knots[index - 1].1.abs_diff(self.knots[index].1) == 2 {
+            self.knots[index].1 = (self.knots[index].1 + self.knots[index - 1].1) / 2;
+            self.knots[index].0 = self.knots[index - 1].0;
+        }
+    }
+
+    const fn get_tail_visited_points(&self) -> &HashSet<Point> {
+        &self.tail_visited
+    }
+}
+
+const WINDOW: i32 = 10;
+
+pub struct Rope {
+    path: Path,
+    motions: Vec<Motion>,
+    motion: usize,
+    moved: u8,
+}
+
+impl Rope {
+    fn new(input: &str, knots: usize) -> Self {
+        let (_, motions) = motions(input).unwrap();
+        Self {
+            path: Path::new(knots),
+            motions,
+            motion: 0,
+            moved: 0,
+        }
+    }
+}
+
+impl Simulation for Rope {
+    fn frame(&self) -> Frame {
+        let head = self.path.knots[0];
+        (head.1 - WINDOW..=head.1 + WINDOW)
+            .rev()
+            .map(|y| {
+                (head.0 - 2 * WINDOW..=head.0 + 2 * WINDOW)
+                    .map(
+                        |x| match self.path.knots.iter().position(|knot| *knot == (x, y)) {
+                            Some(0) => 'H',
+                            Some(i) => char::from_digit(i as u32, 36).unwrap_or('T'),
+                            None if (x, y) == (0, 0) => 's',
+                            None if self.path.tail_visited.contains(&(x, y)) => '#',
+                            None => '.',
+                        },
+                    )
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn step(&mut self) -> bool {
+        while let Some(motion) = self.motions.get(self.motion) {
+            if self.moved < motion.amount {
+                self.path.step(motion.direction);
+                self.moved += 1;
+                if self.moved == motion.amount {
+                    self.motion += 1;
+                    self.moved = 0;
+                }
+                return true;
+            }
+            self.motion += 1;
+            self.moved = 0;
+        }
+        false
+    }
+
+    fn next_step(&self) -> Option<String> {
+        let motion = self.motions.get(self.motion)?;
+        Some(format!(
+            "{:?} ({} of {}) in motion {} of {}",
+            motion.direction,
+            self.moved + 1,
+            motion.amount,
+            self.motion + 1,
+            self.motions.len()
+        ))
+    }
+
+    fn watches(&self) -> Vec<(&'static str, i64)> {
+        let head = self.path.knots[0];
+        let tail = *self.path.knots.last().unwrap();
+        vec![
+            ("head_x", head.0.into()),
+            ("head_y", head.1.into()),
+            ("tail_x", tail.0.into()),
+            ("tail_y", tail.1.into()),
+            ("visited", self.path.tail_visited.len() as i64),
+        ]
+    }
+}
+
+pub fn rope(input: &str) -> Rope {
+    Rope::new(input, 2)
+}
+
+pub fn long_rope(input: &str) -> Rope {
+    Rope::new(input, 10)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut rope = rope(input);
+    while rope.step() {}
+    rope.path.get_tail_visited_points().len().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut rope = long_rope(input);
+    while rope.step() {}
+    rope.path.get_tail_visited_points().len().into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA1: &str = r"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+    const DATA2: &str = r"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(DATA1), "13");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(DATA1), "1");
+        assert_eq!(part2(DATA2), "36");
+    }
+
+    #[test]
+    fn test_rope_watches() {
+        let mut rope = rope(DATA1);
+        for _ in 0..4 {
+            rope.step();
+        }
+        assert_eq!(
+            rope.watches(),
+            [
+                ("head_x", 4),
+                ("head_y", 0),
+                ("tail_x", 3),
+                ("tail_y", 0),
+                ("visited", 4)
+            ]
+        );
+        assert_eq!(
+            rope.next_step(),
+            Some("Up (1 of 4) in motion 2 of 8".to_string())
+        );
+    }
+}
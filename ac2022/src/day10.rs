@@ -1,3 +1,5 @@
+use lib::viz::{Frame, Simulation};
+use lib::Answer;
 use nom::branch::alt;
 use nom::character::complete;
 use nom::character::complete::{alpha1, newline, space1};
@@ -80,7 +82,7 @@ impl Cpu {
                 unreachable!()
             }
         }
-        self.index != self.instructions.len()
+        self.index != self.instructions.len() || matches!(self.busy, Busy::AddX(..))
     }
 
     fn draw(&self) -> bool {
@@ -88,24 +90,83 @@ impl Cpu {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub struct Crt {
+    cpu: Cpu,
+    screen: Vec<bool>,
+    running: bool,
+}
+
+impl Simulation for Crt {
+    fn frame(&self) -> Frame {
+        (0..6)
+            .map(|row| {
+                (0..40)
+                    .map(|column| match self.screen.get(row * 40 + column) {
+                        Some(true) => '#',
+                        Some(false) => '.',
+                        None => ' ',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn step(&mut self) -> bool {
+        if !self.running {
+            return false;
+        }
+        self.screen.push(self.cpu.draw());
+        self.running = self.cpu.cycle();
+        true
+    }
+
+    fn next_step(&self) -> Option<String> {
+        match self.cpu.busy {
+            Busy::AddX(x, _) => Some(format!("finish addx {x}")),
+            Busy::No => self
+                .cpu
+                .instructions
+                .get(self.cpu.index)
+                .map(|instruction| match instruction {
+                    Instruction::Noop => "noop".to_string(),
+                    Instruction::AddX(x) => format!("start addx {x}"),
+                }),
+        }
+    }
+
+    fn watches(&self) -> Vec<(&'static str, i64)> {
+        vec![
+            ("clock", self.cpu.clock.into()),
+            ("x", self.cpu.x.into()),
+            ("pc", self.cpu.index as i64),
+        ]
+    }
+}
+
+pub fn crt(input: &str) -> Crt {
     let (_, data) = instructions(input).unwrap();
-    let mut cpu = Cpu::new(data);
-    while cpu.cycle() {}
-    cpu.strengths.iter().sum::<i32>().to_string()
+    Crt {
+        cpu: Cpu::new(data),
+        screen: vec![],
+        running: true,
+    }
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, data) = instructions(input).unwrap();
     let mut cpu = Cpu::new(data);
-    let mut screen = vec![];
-    while cpu.cycle() {
-        screen.push(cpu.draw());
-    }
-    let text: Vec<char> = screen.iter().map(|x| if *x { '#' } else { ' ' }).collect();
-    text.chunks(40)
-        .map(|x| "\n".to_string() + &x.iter().collect::<String>())
-        .collect()
+    while cpu.cycle() {}
+    cpu.strengths.iter().sum::<i32>().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut crt = crt(input);
+    while crt.step() {}
+    crt.screen
+        .chunks(40)
+        .map(<[bool]>::to_vec)
+        .collect::<Vec<_>>()
+        .into()
 }
 
 #[cfg(test)]
@@ -267,6 +328,19 @@ noop
 
     #[test]
     fn test_part2() {
-        part2(DATA1);
+        assert_eq!(
+            part2(DATA1),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+
+    #[test]
+    fn test_part2_letters() {
+        assert_eq!(part2(include_str!("../input/p10")), "RUAKHBEK");
     }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use lib::Answer;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{digit1, newline};
@@ -221,7 +222,7 @@ fn run_monkeys2(monkeys: &mut [SmartMonkey]) {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, mut data) = monkeys(input).unwrap();
     for _ in 1..=20 {
         run_monkeys(&mut data);
@@ -233,10 +234,10 @@ pub fn part1(input: &str) -> String {
         .map(|x| x.inspections)
         .reduce(|acc, new| acc * new)
         .unwrap()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, data) = monkeys(input).unwrap();
     let mut smart_monkeys = SmartMonkey::from_monkeys(&data);
     for _ in 1..=10000 {
@@ -250,7 +251,7 @@ pub fn part2(input: &str) -> String {
         .map(|x| x.inspections)
         .reduce(|acc, new| acc * new)
         .unwrap()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     character::{
         complete::{anychar, newline},
@@ -117,7 +118,7 @@ fn find_tile(grid: &Grid, target: &Tile) -> Option<Point> {
     })
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, data) = grid(input).unwrap();
     let start = find_tile(&data, &Tile::Start).unwrap();
     let end = find_tile(&data, &Tile::End).unwrap();
@@ -128,10 +129,10 @@ pub fn part1(input: &str) -> String {
         |point| *point == end,
     )
     .unwrap();
-    cost.to_string()
+    cost.into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, data) = grid(input).unwrap();
     let end = find_tile(&data, &Tile::End).unwrap();
     let path = pathfinding::prelude::bfs(
@@ -140,7 +141,7 @@ pub fn part2(input: &str) -> String {
         |point| point.get_height(&data) == u32::from('a'),
     )
     .unwrap();
-    (path.len() - 1).to_string()
+    (path.len() - 1).into()
 }
 
 #[cfg(test)]
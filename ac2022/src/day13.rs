@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 
+use lib::Answer;
 use nom::{
     branch::alt,
     character::complete::{char, newline},
@@ -10,7 +11,7 @@ use nom::{
     IResult,
 };
 
-fn packet(input: &str) -> IResult<&str, Packet> {
+pub fn packet(input: &str) -> IResult<&str, Packet> {
     alt((
         map(nom::character::complete::u32, Packet::Number),
         delimited(
@@ -21,14 +22,14 @@ fn packet(input: &str) -> IResult<&str, Packet> {
     ))(input)
 }
 
-fn parse_signal(input: &str) -> IResult<&str, Vec<PacketPair>> {
+pub fn parse_signal(input: &str) -> IResult<&str, Vec<PacketPair>> {
     separated_list1(count(newline, 2), separated_pair(packet, newline, packet))(input)
 }
 
-type PacketPair = (Packet, Packet);
+pub type PacketPair = (Packet, Packet);
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-enum Packet {
+pub enum Packet {
     List(Vec<Packet>),
     Number(u32),
 }
@@ -66,7 +67,7 @@ impl Display for Packet {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     parse_signal(input)
         .unwrap()
         .1
@@ -74,14 +75,14 @@ pub fn part1(input: &str) -> String {
         .enumerate()
         .filter_map(|(i, (left, right))| if left <= right { Some(i + 1) } else { None })
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 fn transform_data(data: Vec<PacketPair>) -> Vec<Packet> {
     data.into_iter().flat_map(<[Packet; 2]>::from).collect()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let mut signal = transform_data(parse_signal(input).unwrap().1);
     let divs = vec![packet("[[2]]").unwrap().1, packet("[[6]]").unwrap().1];
     signal.append(&mut divs.clone());
@@ -95,12 +96,58 @@ pub fn part2(input: &str) -> String {
                 .unwrap()
         })
         .product::<usize>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use lib::generate::Rng;
+    use lib::prop::{for_all, from_fn, vecs, Strategy};
+
+    fn random_packet(rng: &mut Rng, depth: usize) -> Packet {
+        if depth == 0 || rng.chance(0.3) {
+            Packet::Number(rng.below(11) as u32)
+        } else {
+            Packet::List(
+                (0..rng.below(5))
+                    .map(|_| random_packet(rng, depth - 1))
+                    .collect(),
+            )
+        }
+    }
+
+    fn shrink_packet(packet: &Packet) -> Vec<Packet> {
+        match packet {
+            Packet::Number(0) => vec![],
+            Packet::Number(x) => vec![
+                Packet::Number(0),
+                Packet::Number(x / 2),
+                Packet::Number(x - 1),
+            ],
+            Packet::List(items) => {
+                let mut candidates = items.clone();
+                for i in 0..items.len() {
+                    let mut fewer = items.clone();
+                    fewer.remove(i);
+                    candidates.push(Packet::List(fewer));
+                    for simpler in shrink_packet(&items[i]) {
+                        let mut simplified = items.clone();
+                        simplified[i] = simpler;
+                        candidates.push(Packet::List(simplified));
+                    }
+                }
+                candidates
+            }
+        }
+    }
+
+    fn packets() -> impl Strategy<Value = Packet> {
+        from_fn(
+            |rng: &mut Rng, size| random_packet(rng, 1 + size / 25),
+            shrink_packet,
+        )
+    }
 
     const DATA1: &str = r"[1,1,3,1,1]
 [1,1,5,1,1]
@@ -135,4 +182,30 @@ mod test {
     fn test_part2() {
         assert_eq!(part2(DATA1), "140");
     }
+
+    #[test]
+    fn test_packet_round_trip() {
+        for_all(&packets(), |expected| {
+            assert_eq!(packet(&expected.to_string()), Ok(("", expected.clone())));
+        });
+        for_all(&vecs((packets(), packets()), 1..=10), |pairs| {
+            let signal = pairs
+                .iter()
+                .map(|(left, right)| format!("{left}\n{right}"))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            assert_eq!(parse_signal(&signal), Ok(("", pairs.clone())));
+        });
+    }
+
+    #[test]
+    fn test_packet_total_order() {
+        for_all(&(packets(), packets(), packets()), |(a, b, c)| {
+            assert_eq!(a.cmp(a), Ordering::Equal);
+            assert_eq!(a.cmp(b), b.cmp(a).reverse());
+            if a <= b && b <= c {
+                assert!(a <= c, "{a} <= {b} <= {c}");
+            }
+        });
+    }
 }
@@ -1,7 +1,9 @@
 use std::cmp::{max, min};
-use std::fmt::{Display, Formatter};
-use std::ops::{Index, IndexMut};
 
+use lib::grid::{Bounds, Cells, Coord, SparseGrid};
+use lib::image::Image;
+use lib::viz::{Frame, Simulation};
+use lib::Answer;
 use nom::bytes::complete::tag;
 use nom::{
     character::complete::{char, newline},
@@ -48,13 +50,12 @@ fn formation(input: &str) -> IResult<&str, Vec<Vec<Point>>> {
     separated_list1(newline, path)(input)
 }
 
+const SOURCE: Coord = (500, 0);
+
 #[derive(Debug, Clone)]
-struct CaveWall {
-    grid: Vec<Vec<Tile>>,
-    x_min: usize,
-    y_min: usize,
-    x_max: usize,
-    y_max: usize,
+pub struct CaveWall {
+    cells: SparseGrid<Tile>,
+    y_max: i64,
     has_floor: bool,
 }
 
@@ -64,11 +65,11 @@ impl CaveWall {
             if let [start, end] = line {
                 if start.x == end.x {
                     for y in min(start.y, end.y)..=max(start.y, end.y) {
-                        self[(start.x, y)] = Tile::Rock;
+                        self.cells.insert((start.x as i64, y as i64), Tile::Rock);
                     }
                 } else if start.y == end.y {
                     for x in min(start.x, end.x)..=max(start.x, end.x) {
-                        self[(x, start.y)] = Tile::Rock;
+                        self.cells.insert((x as i64, start.y as i64), Tile::Rock);
                     }
                 } else {
                     panic!("non-straight line")
@@ -77,77 +78,53 @@ impl CaveWall {
         }
     }
 
-    fn drop_sand(&mut self, mut x: usize, mut y: usize) -> bool {
-        if self[(x, y)] == Tile::Sand {
+    fn tile(&self, at: Coord) -> Tile {
+        self.cells.get(at).copied().unwrap_or(Tile::Air)
+    }
+
+    fn drop_sand(&mut self, (mut x, mut y): Coord) -> bool {
+        if self.tile((x, y)) == Tile::Sand {
             return false;
         }
         loop {
             if y == self.y_max {
                 return if self.has_floor {
-                    self[(x, y)] = Tile::Sand;
+                    self.cells.insert((x, y), Tile::Sand);
                     true
                 } else {
                     false
                 };
-            } else if self[(x, y + 1)] == Tile::Air {
-                y += 1;
-            } else if x == self.x_min {
-                return false;
-            } else if self[(x - 1, y + 1)] == Tile::Air {
-                y += 1;
-                x -= 1;
-            } else if x == self.x_max {
-                return false;
-            } else if self[(x + 1, y + 1)] == Tile::Air {
+            }
+            if let Some(dx) = [0, -1, 1]
+                .into_iter()
+                .find(|dx| self.tile((x + dx, y + 1)) == Tile::Air)
+            {
+                x += dx;
                 y += 1;
-                x += 1;
             } else {
-                self[(x, y)] = Tile::Sand;
+                self.cells.insert((x, y), Tile::Sand);
                 return true;
             }
         }
     }
 
     fn count(&self, tile: Tile) -> usize {
-        self.grid.iter().flatten().filter(|x| **x == tile).count()
-    }
-}
-
-impl Index<(usize, usize)> for CaveWall {
-    type Output = Tile;
-
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let (x, y) = index;
-        &self.grid[y - self.y_min][x - self.x_min]
-    }
-}
-
-impl IndexMut<(usize, usize)> for CaveWall {
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        let (x, y) = index;
-        &mut self.grid[y - self.y_min][x - self.x_min]
+        self.cells.iter().filter(|(_, x)| **x == tile).count()
     }
 }
 
 impl TryFrom<&[Vec<Point>]> for CaveWall {
     type Error = String;
     fn try_from(formation: &[Vec<Point>]) -> Result<Self, Self::Error> {
-        let x_min = 0;
-        let y_min = 0;
-        let x_max = 1000;
         let y_max = *formation
             .iter()
             .flatten()
             .map(|Point { y, .. }| y)
             .max()
-            .ok_or_else(|| "invalid".to_string())?
+            .ok_or_else(|| "invalid".to_string())? as i64
             + 1;
-        let grid = vec![vec![Tile::Air; x_max - x_min + 1]; y_max - y_min + 1];
         let mut result = Self {
-            grid,
-            x_min,
-            y_min,
-            x_max,
+            cells: SparseGrid::new(),
             y_max,
             has_floor: false,
         };
@@ -158,54 +135,80 @@ impl TryFrom<&[Vec<Point>]> for CaveWall {
     }
 }
 
-impl Display for CaveWall {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.grid
-                .iter()
-                .map(|row| row.iter().map(Tile::to_string).collect::<String>())
-                .reduce(|x, y| format!("{x}\n{y}"))
-                .unwrap()
-        )
+impl CaveWall {
+    /// The triangle sand can reach with a floor, from the top of the cave
+    /// down to the floor.
+    const fn visible(&self) -> Bounds {
+        Bounds {
+            min: (SOURCE.0 - self.y_max - 1, 0),
+            max: (SOURCE.0 + self.y_max + 1, self.y_max),
+        }
     }
 }
 
+impl Simulation for CaveWall {
+    fn frame(&self) -> Frame {
+        self.cells.render_bounds(self.visible(), |tile| {
+            tile.copied().unwrap_or(Tile::Air).into()
+        })
+    }
+
+    fn step(&mut self) -> bool {
+        self.drop_sand(SOURCE)
+    }
+}
+
+pub fn sand(input: &str) -> CaveWall {
+    let (_, data) = formation(input).unwrap();
+    CaveWall::try_from(&*data).unwrap()
+}
+
+pub fn sand_with_floor(input: &str) -> CaveWall {
+    let mut wall = sand(input);
+    wall.has_floor = true;
+    wall
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-enum Tile {
+pub enum Tile {
     Air,
     Rock,
     Sand,
 }
 
-impl Display for Tile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Air => '.',
-                Self::Rock => '#',
-                Self::Sand => 'O',
-            }
-        )
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'O',
+        }
     }
 }
 
-pub fn part1(input: &str) -> String {
-    let (_, data) = formation(input).unwrap();
-    let mut wall = CaveWall::try_from(&*data).unwrap();
-    while wall.drop_sand(500, 0) {}
-    wall.count(Tile::Sand).to_string()
+pub fn settled_sand(input: &str) -> Image {
+    let mut wall = sand_with_floor(input);
+    while wall.step() {}
+    let bounds = wall.visible();
+    Image::from_fn(bounds.width(), bounds.height(), |x, y| {
+        match wall.tile((bounds.min.0 + x as i64, y as i64)) {
+            Tile::Air => [16, 16, 32],
+            Tile::Rock => [120, 120, 140],
+            Tile::Sand => [230, 190, 60],
+        }
+    })
 }
 
-pub fn part2(input: &str) -> String {
-    let (_, data) = formation(input).unwrap();
-    let mut wall = CaveWall::try_from(&*data).unwrap();
-    wall.has_floor = true;
-    while wall.drop_sand(500, 0) {}
-    wall.count(Tile::Sand).to_string()
+pub fn part1(input: &str) -> Answer {
+    let mut wall = sand(input);
+    while wall.step() {}
+    wall.count(Tile::Sand).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut wall = sand_with_floor(input);
+    while wall.step() {}
+    wall.count(Tile::Sand).into()
 }
 
 #[cfg(test)]
@@ -225,4 +228,14 @@ mod test {
     fn test_part2() {
         assert_eq!(part2(DATA1), "93");
     }
+
+    #[test]
+    fn test_sand_frame() {
+        let mut wall = sand(DATA1);
+        while wall.step() {}
+        let frame = wall.frame();
+        assert_eq!(frame.len(), 11);
+        assert_eq!(frame[0].len(), 23);
+        assert_eq!(&frame[9][5..16], "#########..");
+    }
 }
@@ -1,5 +1,6 @@
 #![allow(clippy::cast_possible_wrap)]
 
+use lib::Answer;
 use nom::{
     bytes::complete::tag, character::complete::newline, combinator::map, multi::separated_list1,
     sequence::separated_pair, IResult,
@@ -78,7 +79,7 @@ impl Location for Beacon {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, sensors) = parse_data(input).unwrap();
     let min = sensors
         .iter()
@@ -94,7 +95,7 @@ pub fn part1(input: &str) -> String {
     let y = 2_000_000;
     #[cfg(test)]
     let y = 10;
-    get_closed_spaces(&sensors, min, max, y).to_string()
+    get_closed_spaces(&sensors, min, max, y).into()
 }
 
 fn get_closed_spaces(sensors: &[Sensor], min: i32, max: i32, y: i32) -> i32 {
@@ -120,14 +121,14 @@ fn get_closed_spaces(sensors: &[Sensor], min: i32, max: i32, y: i32) -> i32 {
     }
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, sensors) = parse_data(input).unwrap();
     #[cfg(not(test))]
     let max = 4_000_000;
     #[cfg(test)]
     let max = 20;
     let (x, y) = find_gap(&sensors, max);
-    (i64::from(x) * 4_000_000 + i64::from(y)).to_string()
+    (i64::from(x) * 4_000_000 + i64::from(y)).into()
 }
 
 fn find_gap(sensors: &[Sensor], max: i32) -> (i32, i32) {
@@ -3,6 +3,7 @@ use std::{
     hash::Hash,
 };
 
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
@@ -138,11 +139,11 @@ fn all_paths_starting_at(
     out
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, data) = cave_system(input).unwrap();
     let useful_valve_paths = relative_valve_costs(&data);
     let (_, flow) = optimal_path(&useful_valve_paths);
-    flow.to_string()
+    flow.into()
 }
 
 fn optimal_path_pair(network: &HashMap<Valve, HashMap<String, u32>>) -> u32 {
@@ -182,11 +183,11 @@ fn no_overlap<T: Eq + Hash>(path1: &HashSet<T>, path2: &HashSet<T>) -> bool {
     path1.is_disjoint(path2)
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, data) = cave_system(input).unwrap();
     let useful_valve_paths = relative_valve_costs(&data);
     let flow = optimal_path_pair(&useful_valve_paths);
-    flow.to_string()
+    flow.into()
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
-use itertools::Itertools;
-use std::{fmt::Display, ops::Range};
+use lib::viz::{Frame, Simulation};
+use lib::Answer;
+use std::ops::Range;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Move {
@@ -186,25 +187,30 @@ impl PartialEq for SaveState {
 type EnumeratedEndless<'a, T> =
     std::iter::Flatten<std::iter::Repeat<std::iter::Enumerate<std::slice::Iter<'a, T>>>>;
 
+type EnumeratedCycle<T> = std::iter::Cycle<std::iter::Enumerate<std::vec::IntoIter<T>>>;
+
+const VISIBLE_ROWS: usize = 40;
+
 #[derive(Debug, Clone)]
-struct Board<'a> {
+pub struct Board {
     grid: Vec<[Rock; 7]>,
     truncated_height: usize,
+    rocks: usize,
     block_range: Range<usize>,
     savestates: Vec<SaveState>,
-    moves_loop: EnumeratedEndless<'a, Move>,
+    moves_loop: EnumeratedCycle<Move>,
     blocks_loop: EnumeratedEndless<'static, [[Rock; 7]; 7]>,
 }
 
-impl Display for Board<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.grid
-                .iter()
-                .rev()
-                .map(|arr| format!(
+impl Simulation for Board {
+    fn frame(&self) -> Frame {
+        let mut frame: Frame = self
+            .grid
+            .iter()
+            .rev()
+            .take(VISIBLE_ROWS)
+            .map(|arr| {
+                format!(
                     "|{}|",
                     arr.iter()
                         .map(|r| match r {
@@ -213,21 +219,43 @@ impl Display for Board<'_> {
                             Rock::Moving => '@',
                         })
                         .collect::<String>()
-                ))
-                .join("\n")
-                + "\n+-------+\n"
-        )
+                )
+            })
+            .collect();
+        if self.grid.len() <= VISIBLE_ROWS && self.truncated_height == 0 {
+            frame.push("+-------+".to_string());
+        }
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        self.simulate(1);
+        true
+    }
+
+    fn next_step(&self) -> Option<String> {
+        let (shape, _) = self.blocks_loop.clone().next()?;
+        let (jet, _) = self.moves_loop.clone().next()?;
+        Some(format!("drop rock shape {shape} starting at jet {jet}"))
+    }
+
+    fn watches(&self) -> Vec<(&'static str, i64)> {
+        vec![
+            ("height", self.height() as i64),
+            ("rocks", self.rocks as i64),
+        ]
     }
 }
 
-impl<'a> Board<'a> {
-    fn new(moves: &'a [Move]) -> Self {
+impl Board {
+    fn new(moves: Vec<Move>) -> Self {
         Self {
             grid: vec![],
             truncated_height: 0,
+            rocks: 0,
             block_range: 0..0,
             savestates: vec![],
-            moves_loop: std::iter::repeat(moves.iter().enumerate()).flatten(),
+            moves_loop: moves.into_iter().enumerate().cycle(),
             blocks_loop: std::iter::repeat(BLOCKS.iter().enumerate()).flatten(),
         }
     }
@@ -310,6 +338,7 @@ impl<'a> Board<'a> {
         for _ in 0..count {
             let (_, block) = self.blocks_loop.next().expect("infinite iterator");
             self.grid.extend(block);
+            self.rocks += 1;
             self.block_range = self.grid.len() - 4
                 ..self.grid.len()
                     - (4 - block.iter().filter(|x| x.contains(&Rock::Moving)).count());
@@ -332,6 +361,7 @@ impl<'a> Board<'a> {
         for i in 1..=count {
             let (block_index, block) = self.blocks_loop.next().expect("infinite iterator");
             self.grid.extend(block);
+            self.rocks += 1;
             self.block_range = self.grid.len() - 4
                 ..self.grid.len()
                     - (4 - block.iter().filter(|x| x.contains(&Rock::Moving)).count());
@@ -403,7 +433,7 @@ impl<'a> Board<'a> {
         None
     }
 
-    fn height(&mut self) -> usize {
+    const fn height(&self) -> usize {
         self.grid.len() + self.truncated_height
     }
 
@@ -427,8 +457,8 @@ const fn check_move(src: Rock, dest: Rock) -> Option<(Rock, Rock)> {
     }
 }
 
-pub fn part1(input: &str) -> String {
-    let data: Vec<Move> = input
+fn parse_moves(input: &str) -> Vec<Move> {
+    input
         .chars()
         .filter(|x| *x != '\n')
         .map(|c| match c {
@@ -436,25 +466,23 @@ pub fn part1(input: &str) -> String {
             '>' => Move::Right,
             _ => panic!("wrong input"),
         })
-        .collect();
-    let mut board = Board::new(&data);
+        .collect()
+}
+
+pub fn tower(input: &str) -> Board {
+    Board::new(parse_moves(input))
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut board = tower(input);
     board.simulate_until_cycle(2022);
-    board.height().to_string()
+    board.height().into()
 }
 
-pub fn part2(input: &str) -> String {
-    let data: Vec<Move> = input
-        .chars()
-        .filter(|x| *x != '\n')
-        .map(|c| match c {
-            '<' => Move::Left,
-            '>' => Move::Right,
-            _ => panic!("wrong input"),
-        })
-        .collect();
-    let mut board = Board::new(&data);
+pub fn part2(input: &str) -> Answer {
+    let mut board = tower(input);
     board.simulate_until_cycle(1_000_000_000_000);
-    board.height().to_string()
+    board.height().into()
 }
 
 #[cfg(test)]
@@ -468,6 +496,23 @@ mod test {
         assert_eq!(part1(DATA1), "3068");
     }
 
+    #[test]
+    fn test_tower_frame() {
+        let mut board = tower(DATA1);
+        board.step();
+        board.step();
+        assert_eq!(
+            board.frame(),
+            [
+                "|...#...|",
+                "|..###..|",
+                "|...#...|",
+                "|..####.|",
+                "+-------+"
+            ]
+        );
+    }
+
     #[test]
     #[ignore]
     fn test_part2() {
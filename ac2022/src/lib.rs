@@ -0,0 +1,75 @@
+use lib::generate::Generator;
+use lib::image::Picture;
+use lib::viz::Animation;
+use lib::{animation, picture, solution, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(01, 1),
+    solution!(01, 2),
+    solution!(02, 1),
+    solution!(02, 2),
+    solution!(03, 1),
+    solution!(03, 2),
+    solution!(04, 1),
+    solution!(04, 2),
+    solution!(05, 1),
+    solution!(05, 2),
+    solution!(06, 1),
+    solution!(06, 2),
+    solution!(07, 1),
+    solution!(07, 2),
+    solution!(08, 1),
+    solution!(08, 2),
+    solution!(09, 1),
+    solution!(09, 2),
+    solution!(10, 1),
+    solution!(10, 2),
+    solution!(11, 1),
+    solution!(11, 2),
+    solution!(12, 1),
+    solution!(12, 2),
+    solution!(13, 1),
+    solution!(13, 2),
+    solution!(14, 1),
+    solution!(14, 2),
+    solution!(15, 1),
+    solution!(15, 2),
+    solution!(16, 1),
+    solution!(16, 2),
+    solution!(17, 1),
+    solution!(17, 2),
+];
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const ANIMATIONS: &[Animation] = &[
+    animation!(09, rope),
+    animation!(09, long_rope),
+    animation!(10, crt),
+    animation!(14, sand),
+    animation!(14, sand_with_floor),
+    animation!(17, tower),
+];
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const PICTURES: &[Picture] = &[picture!(14, settled_sand)];
+
+pub const GENERATORS: &[Generator] = &[];
@@ -1,59 +1,8 @@
 extern crate core;
 
-use lib::solution;
+use ac2022::{ANIMATIONS, GENERATORS, PICTURES, SOLUTIONS};
+use lib::run;
 
-mod p01;
-mod p02;
-mod p03;
-mod p04;
-mod p05;
-mod p06;
-mod p07;
-mod p08;
-mod p09;
-mod p10;
-mod p11;
-mod p12;
-mod p13;
-mod p14;
-mod p15;
-mod p16;
-mod p17;
-
-#[allow(clippy::zero_prefixed_literal)]
 fn main() {
-    solution!(01, 1);
-    solution!(01, 2);
-    solution!(02, 1);
-    solution!(02, 2);
-    solution!(03, 1);
-    solution!(03, 2);
-    solution!(04, 1);
-    solution!(04, 2);
-    solution!(05, 1);
-    solution!(05, 2);
-    solution!(06, 1);
-    solution!(06, 2);
-    solution!(07, 1);
-    solution!(07, 2);
-    solution!(08, 1);
-    solution!(08, 2);
-    solution!(09, 1);
-    solution!(09, 2);
-    solution!(10, 1);
-    solution!(10, 2);
-    solution!(11, 1);
-    solution!(11, 2);
-    solution!(12, 1);
-    solution!(12, 2);
-    solution!(13, 1);
-    solution!(13, 2);
-    solution!(14, 1);
-    solution!(14, 2);
-    solution!(15, 1);
-    solution!(15, 2);
-    solution!(16, 1);
-    solution!(16, 2);
-    solution!(17, 1);
-    solution!(17, 2);
+    run(SOLUTIONS, ANIMATIONS, PICTURES, GENERATORS);
 }
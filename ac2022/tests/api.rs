@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+
+use ac2022::day13::{packet, Packet};
+use ac2022::{day17, SOLUTIONS};
+
+#[test]
+fn test_parsers_are_reusable() {
+    let (rest, parsed) = packet("[1,[2]]").unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(
+        parsed,
+        Packet::List(vec![
+            Packet::Number(1),
+            Packet::List(vec![Packet::Number(2)])
+        ])
+    );
+}
+
+#[test]
+fn test_parts_are_callable() {
+    assert_eq!(
+        day17::part1(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"),
+        "3068"
+    );
+}
+
+#[test]
+fn test_solutions_are_unique() {
+    let mut seen = HashSet::new();
+    for solution in SOLUTIONS {
+        assert!(
+            seen.insert((solution.day, solution.part, solution.variant)),
+            "day {} part {} variant {} registered twice",
+            solution.day,
+            solution.part,
+            solution.variant
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -6,7 +7,7 @@ use nom::{
     IResult,
 };
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     input
         .lines()
         .map(|line| {
@@ -22,7 +23,7 @@ pub fn part1(input: &str) -> String {
                     .expect("impossible")
         })
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 fn number(input: &str) -> IResult<&str, Option<u32>> {
@@ -48,7 +49,7 @@ fn number(input: &str) -> IResult<&str, Option<u32>> {
     ))(input)
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     input
         .lines()
         .map(|line| {
@@ -62,7 +63,7 @@ pub fn part2(input: &str) -> String {
         })
         .map(|x| x.first().expect("no digit on line") * 10 + x.last().expect("no digit on line"))
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
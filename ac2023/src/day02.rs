@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -58,7 +59,7 @@ fn cube(input: &str) -> IResult<&str, (Colour, u32)> {
     Ok((input, (colour, amount)))
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, result) = parse(input).expect("parsing failed");
     result
         .iter()
@@ -72,10 +73,10 @@ pub fn part1(input: &str) -> String {
         })
         .map(|(i, _)| i + 1)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, result) = parse(input).expect("parsing failed");
     result
         .iter()
@@ -99,7 +100,7 @@ pub fn part2(input: &str) -> String {
                 .product::<u32>()
         })
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use lib::Answer;
+
 const fn is_symbol(c: char) -> bool {
     !c.is_ascii_digit() && c != '.'
 }
@@ -8,7 +10,7 @@ const fn is_gear(c: char) -> bool {
     c == '*'
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let lines = input.lines().collect::<Vec<&str>>();
     let mut parts: Vec<u32> = vec![];
     for (i, &line) in lines.iter().enumerate() {
@@ -48,7 +50,7 @@ pub fn part1(input: &str) -> String {
             );
         }
     }
-    parts.into_iter().sum::<u32>().to_string()
+    parts.into_iter().sum::<u32>().into()
 }
 
 fn is_touching_symbol(j: usize, line: &str) -> bool {
@@ -100,7 +102,7 @@ struct Number {
     gears: BTreeSet<(usize, usize)>,
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let lines = input.lines().collect::<Vec<&str>>();
     let mut parts: Vec<Number> = vec![];
     for (i, &line) in lines.iter().enumerate() {
@@ -151,7 +153,7 @@ pub fn part2(input: &str) -> String {
         .filter(|x| x.len() == 2)
         .map(|x| x[0] * x[1])
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
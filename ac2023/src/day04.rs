@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use lib::Answer;
 use nom::{
     bytes::complete::tag,
     character::complete::{digit1, newline, space0},
@@ -67,12 +68,12 @@ impl Card {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, cards) = cards(input).expect("Parsing failed");
-    cards.iter().map(Card::score).sum::<u32>().to_string()
+    cards.iter().map(Card::score).sum::<u32>().into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, cards) = cards(input).expect("Parsing failed");
     let mut card_counts: HashMap<u32, usize> = cards.iter().map(|x| (x.id, 1)).collect();
     for card in cards {
@@ -81,7 +82,7 @@ pub fn part2(input: &str) -> String {
             *card_counts.get_mut(&other_card).expect("ID not found") += count;
         }
     }
-    card_counts.values().sum::<usize>().to_string()
+    card_counts.values().sum::<usize>().into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -16,11 +17,11 @@ fn seeds(input: &str) -> IResult<&str, Vec<u64>> {
     preceded(tag("seeds: "), separated_list1(space1, number))(input)
 }
 
-#[derive(Debug, Clone)]
-struct MapRange {
-    dst_start: u64,
-    src_start: u64,
-    length: u64,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapRange {
+    pub dst_start: u64,
+    pub src_start: u64,
+    pub length: u64,
 }
 
 impl MapRange {
@@ -76,7 +77,7 @@ impl MapRange {
             (
                 vec![SeedRange {
                     start: self.dst_start + x.start - self.src_start,
-                    length: x.end() - self.src_start + 1,
+                    length: self.src_end() - x.start + 1,
                 }],
                 vec![SeedRange {
                     start: self.src_end() + 1,
@@ -130,9 +131,9 @@ fn map_name(input: &str) -> IResult<&str, (Property, Property)> {
     )(input)
 }
 
-#[derive(Debug)]
-struct PropertyMap {
-    ranges: Vec<MapRange>,
+#[derive(Debug, PartialEq, Eq)]
+pub struct PropertyMap {
+    pub ranges: Vec<MapRange>,
 }
 
 impl PropertyMap {
@@ -164,7 +165,7 @@ fn property_map(input: &str) -> IResult<&str, PropertyMap> {
     )(input)
 }
 
-fn parse(input: &str) -> IResult<&str, (Vec<u64>, Vec<PropertyMap>)> {
+pub fn parse(input: &str) -> IResult<&str, (Vec<u64>, Vec<PropertyMap>)> {
     all_consuming(terminated(
         separated_pair(
             seeds,
@@ -175,12 +176,12 @@ fn parse(input: &str) -> IResult<&str, (Vec<u64>, Vec<PropertyMap>)> {
     ))(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (mut seeds, maps)) = parse(input).expect("parsing failure");
     for map in maps {
         seeds = seeds.into_iter().map(|x| map.apply(x)).collect();
     }
-    seeds.iter().min().expect("no seeds").to_string()
+    seeds.into_iter().min().expect("no seeds").into()
 }
 
 fn seed_ranges(input: &str) -> IResult<&str, Vec<SeedRange>> {
@@ -198,7 +199,7 @@ fn seed_ranges(input: &str) -> IResult<&str, Vec<SeedRange>> {
 #[derive(Debug, Default, Clone)]
 struct SeedRange {
     start: u64,
-    length: u64,
+    pub length: u64,
 }
 
 impl SeedRange {
@@ -222,7 +223,7 @@ fn parse2(input: &str) -> IResult<&str, (Vec<SeedRange>, Vec<PropertyMap>)> {
     ))(input)
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (mut seeds, maps)) = parse2(input).expect("parsing failure");
     for map in maps {
         seeds = seeds
@@ -236,12 +237,67 @@ pub fn part2(input: &str) -> String {
         .filter(|x| x != &0)
         .min()
         .expect("no seeds")
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use lib::prop::{for_all, ints, vecs};
+
+    const NAMES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    type RawRange = (u64, u64, u64);
+
+    fn property_map_of(ranges: &[RawRange]) -> PropertyMap {
+        PropertyMap {
+            ranges: ranges
+                .iter()
+                .map(|&(dst_start, src_start, length)| MapRange {
+                    dst_start,
+                    src_start,
+                    length,
+                })
+                .collect(),
+        }
+    }
+
+    /// Lays the ranges out one after another so their sources never overlap,
+    /// as in real almanacs; the middle number is the gap before each range.
+    fn disjoint_map(ranges: &[RawRange]) -> PropertyMap {
+        let mut src_start = 0;
+        let ranges: Vec<RawRange> = ranges
+            .iter()
+            .map(|&(dst_start, gap, length)| {
+                src_start += gap;
+                let range = (dst_start, src_start, length);
+                src_start += length;
+                range
+            })
+            .collect();
+        property_map_of(&ranges)
+    }
+
+    fn render(seeds: &[u64], maps: &[PropertyMap]) -> String {
+        let seeds: Vec<String> = seeds.iter().map(u64::to_string).collect();
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+        for (i, map) in maps.iter().enumerate() {
+            almanac += &format!("\n{}-to-{} map:\n", NAMES[i], NAMES[i + 1]);
+            for range in &map.ranges {
+                almanac += &format!("{} {} {}\n", range.dst_start, range.src_start, range.length);
+            }
+        }
+        almanac
+    }
 
     const DATA1: &str = r"seeds: 79 14 55 13
 
@@ -287,4 +343,44 @@ humidity-to-location map:
     fn test_part2() {
         assert_eq!(part2(DATA1), 46.to_string());
     }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let numbers = || ints(0..=u64::from(u32::MAX));
+        let maps = vecs(vecs((numbers(), numbers(), numbers()), 1..=5), 1..=7);
+        for_all(&(vecs(numbers(), 1..=10), maps), |(seeds, maps)| {
+            let maps: Vec<PropertyMap> =
+                maps.iter().map(|ranges| property_map_of(ranges)).collect();
+            let almanac = render(seeds, &maps);
+            assert_eq!(
+                parse(&almanac),
+                Ok(("", (seeds.clone(), maps))),
+                "\n{almanac}"
+            );
+        });
+    }
+
+    #[test]
+    fn test_apply_range_matches_apply() {
+        let ranges = vecs((ints(0..=100), ints(0..=5), ints(1..=10)), 1..=6);
+        for_all(
+            &(ranges, ints(0..=100), ints(1..=30)),
+            |(ranges, start, length)| {
+                let map = disjoint_map(ranges);
+                let mut expected: Vec<u64> =
+                    (*start..start + length).map(|x| map.apply(x)).collect();
+                let mut mapped: Vec<u64> = map
+                    .apply_range(&SeedRange {
+                        start: *start,
+                        length: *length,
+                    })
+                    .iter()
+                    .flat_map(|range| range.start..range.start + range.length)
+                    .collect();
+                expected.sort_unstable();
+                mapped.sort_unstable();
+                assert_eq!(mapped, expected);
+            },
+        );
+    }
 }
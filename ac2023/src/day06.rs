@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     bytes::complete::tag,
     character::complete::{digit1, newline, space1},
@@ -29,7 +30,7 @@ fn parse(input: &str) -> IResult<&str, Vec<(u64, u64)>> {
     })(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, races) = parse(input).expect("parsing failure");
     races
         .iter()
@@ -40,7 +41,7 @@ pub fn part1(input: &str) -> String {
                 .count()
         })
         .product::<usize>()
-        .to_string()
+        .into()
 }
 
 fn number2(input: &str) -> IResult<&str, u64> {
@@ -61,7 +62,7 @@ fn parse2(input: &str) -> IResult<&str, (u64, u64)> {
     separated_pair(times2, newline, distances2)(input)
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (time, distance)) = parse2(input).expect("parsing failed");
     ((time / 2..time)
         .take_while(|v| v * (time - v) > distance)
@@ -70,7 +71,7 @@ pub fn part2(input: &str) -> String {
             .rev()
             .take_while(|v| v * (time - v) > distance)
             .count())
-    .to_string()
+    .into()
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use lib::Answer;
 use nom::{
     branch::alt,
     character::{
@@ -119,7 +120,7 @@ fn parse(input: &str) -> IResult<&str, Vec<Hand>> {
     all_consuming(terminated(separated_list1(newline, hand), newline))(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, mut hands) = parse(input).expect("parsing failed");
     hands.sort();
     hands
@@ -127,7 +128,7 @@ pub fn part1(input: &str) -> String {
         .enumerate()
         .map(|(rank, hand)| (rank + 1) * hand.bid as usize)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
@@ -234,7 +235,7 @@ fn parse2(input: &str) -> IResult<&str, Vec<Hand2>> {
     all_consuming(terminated(separated_list1(newline, hand2), newline))(input)
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, mut hands) = parse2(input).expect("parsing failed");
     hands.sort();
     hands
@@ -242,7 +243,7 @@ pub fn part2(input: &str) -> String {
         .enumerate()
         .map(|(rank, hand)| (rank + 1) * hand.bid as usize)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -30,7 +31,7 @@ struct Node<'a> {
     right: &'a str,
 }
 
-fn node(input: &str) -> IResult<&str, Node> {
+fn node(input: &str) -> IResult<&str, Node<'_>> {
     map(
         separated_pair(
             alphanumeric1,
@@ -45,7 +46,7 @@ fn node(input: &str) -> IResult<&str, Node> {
     )(input)
 }
 
-fn parse(input: &str) -> IResult<&str, (Vec<Direction>, HashMap<&str, Node>)> {
+fn parse(input: &str) -> IResult<&str, (Vec<Direction>, HashMap<&str, Node<'_>>)> {
     all_consuming(terminated(
         separated_pair(
             many1(direction),
@@ -58,7 +59,7 @@ fn parse(input: &str) -> IResult<&str, (Vec<Direction>, HashMap<&str, Node>)> {
     ))(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (directions, nodes)) = parse(input).expect("parsing failure");
     let mut node = nodes.get("AAA").expect("no start");
     let end = nodes.get("ZZZ").expect("no ending");
@@ -70,7 +71,7 @@ pub fn part1(input: &str) -> String {
             })
             .expect("Node not found");
         if node == end {
-            return (i + 1).to_string();
+            return (i + 1).into();
         }
     }
     unreachable!()
@@ -140,14 +141,14 @@ fn lcm(mut input: Vec<usize>) -> usize {
     factors.iter().product()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (directions, nodes)) = parse(input).expect("parsing failure");
     let lengths: Vec<usize> = nodes
         .values()
         .filter(|n| n.name.ends_with('A'))
         .map(|node| path_len(node, &nodes, &directions))
         .collect();
-    lcm(lengths).to_string()
+    lcm(lengths).into()
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use lib::Answer;
 use nom::{
     character::complete::{self, newline, space1},
     combinator::all_consuming,
@@ -26,13 +27,9 @@ fn predict_next(data: &[i64]) -> i64 {
     }
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, patterns) = parse(input).expect("parsing failed");
-    patterns
-        .iter()
-        .map(|x| predict_next(x))
-        .sum::<i64>()
-        .to_string()
+    patterns.iter().map(|x| predict_next(x)).sum::<i64>().into()
 }
 
 fn predict_prev(data: &[i64]) -> i64 {
@@ -43,13 +40,9 @@ fn predict_prev(data: &[i64]) -> i64 {
     }
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, patterns) = parse(input).expect("parsing failed");
-    patterns
-        .iter()
-        .map(|x| predict_prev(x))
-        .sum::<i64>()
-        .to_string()
+    patterns.iter().map(|x| predict_prev(x)).sum::<i64>().into()
 }
 
 #[cfg(test)]
@@ -1,8 +1,8 @@
-use std::{
-    cmp::min,
-    collections::{HashMap, HashSet},
-};
+use std::{cmp::min, collections::HashSet};
 
+use lib::grid::{Bounds, Cells, Coord, SparseGrid, ORTHOGONAL};
+use lib::image::{Image, BLACK};
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -33,7 +33,7 @@ enum Tile {
     Pipe {
         x: i64,
         y: i64,
-        connects: [(i64, i64); 2],
+        connects: [Coord; 2],
         directions: [Direction; 2],
     },
 }
@@ -70,26 +70,26 @@ impl Tile {
         }
     }
 
-    fn get_neighbours<'b>(&self, map: &'b HashMap<(i64, i64), Self>) -> Vec<&'b Self> {
+    fn get_neighbours<'b>(&self, map: &'b SparseGrid<Self>) -> Vec<&'b Self> {
         match self {
-            Self::Start { x, y } => [(*x, y - 1), (*x, y + 1), (x - 1, *y), (x + 1, *y)]
-                .iter()
-                .filter_map(|x| map.get(x))
+            Self::Start { x, y } => map
+                .orthogonal((*x, *y))
+                .map(|(_, tile)| tile)
                 .filter(|v| match v {
                     Self::Start { .. } => unreachable!(),
                     Self::Pipe { connects, .. } => connects.contains(&(*x, *y)),
                 })
                 .collect::<Vec<_>>(),
-            Self::Pipe { connects, .. } => connects.iter().filter_map(|p| map.get(p)).collect(),
+            Self::Pipe { connects, .. } => connects.iter().filter_map(|p| map.get(*p)).collect(),
         }
     }
 
-    fn get_directions(&self, map: &HashMap<(i64, i64), Self>) -> [Direction; 2] {
+    fn get_directions(&self, map: &SparseGrid<Self>) -> [Direction; 2] {
         match self {
             Self::Start { x, y } => {
-                let temp = [(*x, y - 1), (*x, y + 1), (x - 1, *y), (x + 1, *y)]
-                    .iter()
-                    .filter_map(|x| map.get(x))
+                let temp = map
+                    .orthogonal((*x, *y))
+                    .map(|(_, tile)| tile)
                     .filter(|v| match v {
                         Self::Start { .. } => unreachable!(),
                         Self::Pipe { connects, .. } => connects.contains(&(*x, *y)),
@@ -191,13 +191,14 @@ fn parse(input: &str) -> Vec<Tile> {
     grid(Span::new(input)).expect("parsing failed").1
 }
 
-pub fn part1(input: &str) -> String {
-    let tile_map: HashMap<(i64, i64), Tile> = parse(input)
+pub fn part1(input: &str) -> Answer {
+    let tile_map: SparseGrid<Tile> = parse(input)
         .into_iter()
         .map(|t| ((t.x(), t.y()), t))
         .collect();
     let start = tile_map
-        .values()
+        .iter()
+        .map(|(_, tile)| tile)
         .find(|t| matches!(t, Tile::Start { .. }))
         .expect("no start");
     let mut prev_tile1 = start;
@@ -233,40 +234,57 @@ pub fn part1(input: &str) -> String {
         );
         maxlen += 1;
     }
-    maxlen.to_string()
+    maxlen.into()
 }
 
-pub fn part2(input: &str) -> String {
-    let full_tile_map: HashMap<(i64, i64), Tile> = parse(input)
+fn enclosed(input: &str) -> (Vec<Coord>, HashSet<Coord>) {
+    let full_tile_map: SparseGrid<Tile> = parse(input)
         .into_iter()
         .map(|t| ((t.x(), t.y()), t))
         .collect();
     let start = full_tile_map
-        .values()
+        .iter()
+        .map(|(_, tile)| tile)
         .find(|t| matches!(t, Tile::Start { .. }))
         .expect("no start");
     let main_loop = get_main_loop(start, &full_tile_map);
-    let tile_map: HashMap<(i64, i64), Tile> =
-        main_loop.iter().map(|t| ((t.x(), t.y()), **t)).collect();
-
-    let x_min = tile_map.values().map(Tile::x).min().expect("no tiles") - 1;
-    let x_max = tile_map.values().map(Tile::x).max().expect("no tiles") + 1;
-    let y_min = tile_map.values().map(Tile::y).min().expect("no tiles") - 1;
-    let y_max = tile_map.values().map(Tile::y).max().expect("no tiles") + 1;
-
-    let outside = floodfill(x_min, x_max, y_min, y_max, &tile_map);
-    let all_tiles: HashSet<(i64, i64)> = (x_min..=x_max)
-        .flat_map(|x| (y_min..=y_max).map(move |y| (x, y)))
-        .collect();
-    let not_outside: HashSet<(i64, i64)> = all_tiles.difference(&outside).copied().collect();
-    let inside: HashSet<(i64, i64)> = not_outside
-        .difference(&main_loop.iter().map(|t| (t.x(), t.y())).collect())
+    let tile_map: SparseGrid<Tile> = main_loop.iter().map(|t| ((t.x(), t.y()), **t)).collect();
+
+    let bounds = tile_map.bounds().expect("no tiles").grown(1);
+    let outside = floodfill(bounds, &tile_map);
+    let all_tiles: HashSet<Coord> = bounds.coords().collect();
+    let not_outside: HashSet<Coord> = all_tiles.difference(&outside).copied().collect();
+    let main_loop: Vec<Coord> = main_loop.iter().map(|t| (t.x(), t.y())).collect();
+    let inside: HashSet<Coord> = not_outside
+        .difference(&main_loop.iter().copied().collect())
         .copied()
         .collect();
-    inside.len().to_string()
+    (main_loop, inside)
+}
+
+pub fn part2(input: &str) -> Answer {
+    enclosed(input).1.len().into()
+}
+
+pub fn enclosed_tiles(input: &str) -> Image {
+    let (main_loop, inside) = enclosed(input);
+    Image::from_points(
+        main_loop
+            .into_iter()
+            .map(|point| (point, false))
+            .chain(inside.into_iter().map(|point| (point, true))),
+        BLACK,
+        |inside| {
+            if *inside {
+                [60, 200, 90]
+            } else {
+                [120, 120, 140]
+            }
+        },
+    )
 }
 
-fn get_main_loop<'a>(start: &'a Tile, tile_map: &'a HashMap<(i64, i64), Tile>) -> Vec<&'a Tile> {
+fn get_main_loop<'a>(start: &'a Tile, tile_map: &'a SparseGrid<Tile>) -> Vec<&'a Tile> {
     let mut main_loop = vec![start];
     let mut prev_tile = start;
     let mut tile = *start
@@ -287,31 +305,16 @@ fn get_main_loop<'a>(start: &'a Tile, tile_map: &'a HashMap<(i64, i64), Tile>) -
     main_loop
 }
 
-fn floodfill(
-    x_min: i64,
-    x_max: i64,
-    y_min: i64,
-    y_max: i64,
-    tile_map: &HashMap<(i64, i64), Tile>,
-) -> HashSet<(i64, i64)> {
-    let mut todo_stack = vec![(x_min, y_min)];
-    let mut reachable = HashSet::from([(x_min, y_min)]);
+fn floodfill(bounds: Bounds, tile_map: &SparseGrid<Tile>) -> HashSet<Coord> {
+    let mut todo_stack = vec![bounds.min];
+    let mut reachable = HashSet::from([bounds.min]);
     let mut checked = HashSet::new();
 
     while let Some(current) = todo_stack.pop() {
         checked.insert(current);
-        for point in [
-            (current.0 + 1, current.1),
-            (current.0 - 1, current.1),
-            (current.0, current.1 + 1),
-            (current.0, current.1 - 1),
-        ] {
-            if point.0 < x_min
-                || point.0 > x_max
-                || point.1 < y_min
-                || point.1 > y_max
-                || checked.contains(&point)
-            {
+        for (dx, dy) in ORTHOGONAL {
+            let point = (current.0 + dx, current.1 + dy);
+            if !bounds.contains(point) || checked.contains(&point) {
                 continue;
             }
             if walkable(current, point, tile_map) {
@@ -323,14 +326,14 @@ fn floodfill(
     reachable
 }
 
-fn walkable(current: (i64, i64), point: (i64, i64), tile_map: &HashMap<(i64, i64), Tile>) -> bool {
+fn walkable(current: Coord, point: Coord, tile_map: &SparseGrid<Tile>) -> bool {
     if current.0 == point.0 {
         tile_map
-            .get(&(current.0, min(current.1, point.1)))
+            .get((current.0, min(current.1, point.1)))
             .is_none_or(|t| !t.get_directions(tile_map).contains(&Direction::Left))
     } else {
         tile_map
-            .get(&(min(current.0, point.0), current.1))
+            .get((min(current.0, point.0), current.1))
             .is_none_or(|t| !t.get_directions(tile_map).contains(&Direction::Up))
     }
 }
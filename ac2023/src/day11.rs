@@ -0,0 +1,124 @@
+use lib::num::{BigUint, Natural, Overflow};
+use lib::Answer;
+
+fn parse(input: &str) -> Vec<(usize, usize)> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter_map(move |(x, c)| (c == '#').then_some((x, y)))
+        })
+        .collect()
+}
+
+/// The shift of every line up to the last occupied one: each empty line
+/// before it (or itself) adds `factor - 1`.
+fn shifts<N: Natural>(occupied: &[usize], factor: &N) -> Result<Vec<N>, Overflow> {
+    let growth = factor.sub(&N::from_u64(1))?;
+    let max = *occupied.iter().max().expect("no galaxies");
+    let mut shift = N::zero();
+    let mut shifts = Vec::with_capacity(max + 1);
+    for line in 0..=max {
+        if !occupied.contains(&line) {
+            shift = shift.add(&growth)?;
+        }
+        shifts.push(shift.clone());
+    }
+    Ok(shifts)
+}
+
+fn expand<N: Natural>(galaxies: &[(usize, usize)], factor: &N) -> Result<Vec<(N, N)>, Overflow> {
+    let xs: Vec<usize> = galaxies.iter().map(|(x, _)| *x).collect();
+    let ys: Vec<usize> = galaxies.iter().map(|(_, y)| *y).collect();
+    let (shifts_x, shifts_y) = (shifts(&xs, factor)?, shifts(&ys, factor)?);
+    galaxies
+        .iter()
+        .map(|&(x, y)| {
+            Ok((
+                N::from_u64(x as u64).add(&shifts_x[x])?,
+                N::from_u64(y as u64).add(&shifts_y[y])?,
+            ))
+        })
+        .collect()
+}
+
+fn path_length<N: Natural>(galaxy1: &(N, N), galaxy2: &(N, N)) -> Result<N, Overflow> {
+    galaxy1
+        .0
+        .abs_diff(&galaxy2.0)
+        .add(&galaxy1.1.abs_diff(&galaxy2.1))
+}
+
+fn calculate_generic<N: Natural>(input: &str, factor: &N) -> Result<N, Overflow> {
+    let galaxies = expand(&parse(input), factor)?;
+    let mut total = N::zero();
+    for (i, galaxy1) in galaxies.iter().enumerate() {
+        for galaxy2 in &galaxies[i + 1..] {
+            total = total.add(&path_length(galaxy1, galaxy2)?)?;
+        }
+    }
+    Ok(total)
+}
+
+pub fn part1(input: &str) -> Answer {
+    calculate_generic::<u64>(input, &2)
+        .expect("distances overflowed u64")
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    calculate_generic::<u64>(input, &1_000_000)
+        .expect("distances overflowed u64")
+        .into()
+}
+
+pub fn part2_bigint(input: &str) -> Answer {
+    calculate_generic(input, &BigUint::from(1_000_000u64))
+        .expect("bigint cannot overflow")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA1: &str = r"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn test_part1_1() {
+        assert_eq!(part1(DATA1), 374.to_string());
+    }
+
+    #[test]
+    fn test_part2_1() {
+        assert_eq!(calculate_generic(DATA1, &10u64), Ok(1030));
+    }
+
+    #[test]
+    fn test_part2_2() {
+        assert_eq!(calculate_generic(DATA1, &100u64), Ok(8410));
+    }
+
+    #[test]
+    fn test_huge_factor() {
+        let factor = BigUint::from(u64::MAX);
+        assert_eq!(calculate_generic(DATA1, &u64::MAX), Err(Overflow));
+        assert_eq!(
+            calculate_generic(DATA1, &factor).map(|total| total.to_string()),
+            Ok((82 * u128::from(u64::MAX) - 82 + 292).to_string())
+        );
+        assert_eq!(part2_bigint(DATA1), part2(DATA1));
+    }
+}
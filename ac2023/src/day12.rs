@@ -0,0 +1,263 @@
+use lib::generate::Rng;
+use lib::Answer;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, newline, space1},
+    combinator::{map, map_res, value},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Spring {
+    #[default]
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl std::fmt::Display for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Operational => '.',
+                Self::Damaged => '#',
+                Self::Unknown => '?',
+            }
+        )
+    }
+}
+
+fn spring(input: &str) -> IResult<&str, Spring> {
+    alt((
+        value(Spring::Operational, tag(".")),
+        value(Spring::Damaged, tag("#")),
+        value(Spring::Unknown, tag("?")),
+    ))(input)
+}
+
+struct Row {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl std::fmt::Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:?}",
+            self.springs
+                .iter()
+                .map(Spring::to_string)
+                .collect::<String>(),
+            self.groups
+        )
+    }
+}
+
+impl Row {
+    fn calculate_possibilities(&self) -> usize {
+        arrangements(&self.springs, &self.groups)
+    }
+}
+
+/// Can the group of damaged springs start at `start` and be followed by a
+/// working spring (or the end of the row)?
+fn fits(springs: &[Spring], start: usize, length: usize) -> bool {
+    start + length <= springs.len()
+        && springs[start..start + length]
+            .iter()
+            .all(|spring| *spring != Spring::Operational)
+        && springs.get(start + length) != Some(&Spring::Damaged)
+}
+
+/// Counts the ways to fill in the unknown springs so the damaged runs match
+/// `groups`. `ways[i][g]` is the number of arrangements of `groups[g..]`
+/// over `springs[i..]`, filled from the end of the row backwards.
+fn arrangements(springs: &[Spring], groups: &[usize]) -> usize {
+    let mut ways = vec![vec![0; groups.len() + 1]; springs.len() + 1];
+    ways[springs.len()][groups.len()] = 1;
+    for start in (0..springs.len()).rev() {
+        for group in 0..=groups.len() {
+            let mut count = 0;
+            if springs[start] != Spring::Damaged {
+                count += ways[start + 1][group];
+            }
+            if springs[start] != Spring::Operational
+                && group < groups.len()
+                && fits(springs, start, groups[group])
+            {
+                let next = (start + groups[group] + 1).min(springs.len());
+                count += ways[next][group + 1];
+            }
+            ways[start][group] = count;
+        }
+    }
+    ways[0][0]
+}
+
+fn row(input: &str) -> IResult<&str, Row> {
+    map(
+        separated_pair(
+            many1(spring),
+            space1,
+            separated_list1(tag(","), map_res(digit1, str::parse)),
+        ),
+        |(springs, groups)| Row { springs, groups },
+    )(input)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Row>> {
+    separated_list1(newline, row)(input)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (_, rows) = parse(input).expect("parsing failure");
+    let result: usize = rows.iter().map(Row::calculate_possibilities).sum();
+    result.into()
+}
+
+fn expand(row: &Row) -> Row {
+    let mut springs: Vec<Spring> = row.springs.clone();
+    for _ in 0..4 {
+        springs.push(Spring::Unknown);
+        springs.extend_from_slice(&row.springs);
+    }
+    let groups = row.groups.repeat(5);
+    Row { springs, groups }
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (_, rows) = parse(input).expect("parsing failure");
+    let result: usize = rows
+        .iter()
+        .map(|row| expand(row).calculate_possibilities())
+        .sum();
+    result.into()
+}
+
+fn random_row(rng: &mut Rng, length: usize, unknown: f64) -> String {
+    let mut springs = ".".repeat(rng.below(3));
+    let mut groups = vec![];
+    loop {
+        let group = rng.range(1..6);
+        if springs.len() + group > length {
+            break;
+        }
+        springs.push_str(&"#".repeat(group));
+        groups.push(group.to_string());
+        springs.push_str(&".".repeat(rng.range(1..4)));
+    }
+    if groups.is_empty() {
+        springs = "#".to_string();
+        groups.push("1".to_string());
+    }
+    springs.truncate(length.max(springs.trim_end_matches('.').len()));
+    let springs: String = springs
+        .chars()
+        .map(|c| if rng.chance(unknown) { '?' } else { c })
+        .collect();
+    format!("{springs} {}\n", groups.join(","))
+}
+
+pub fn springs(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let length = rng.range(5..21);
+            random_row(rng, length, 0.5)
+        })
+        .collect()
+}
+
+pub fn long_springs(rng: &mut Rng, size: usize) -> String {
+    (0..10).map(|_| random_row(rng, size.max(1), 0.6)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA1: &str = r"#.#.### 1,1,3
+.#...#....###. 1,1,3
+.#.###.#.###### 1,3,1,6
+####.#...#... 4,1,1
+#....######..#####. 1,6,5
+.###.##....# 3,2,1
+";
+
+    const DATA2: &str = r"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn test_part1_1() {
+        assert_eq!(part1(DATA1), 6.to_string());
+    }
+
+    #[test]
+    fn test_part1_2() {
+        assert_eq!(part1(DATA2), 21.to_string());
+    }
+
+    #[test]
+    fn test_part2_1() {
+        assert_eq!(part2(DATA2), 525_152.to_string());
+    }
+
+    fn brute_force(springs: &[Spring], groups: &[usize]) -> usize {
+        let unknown: Vec<usize> = (0..springs.len())
+            .filter(|i| springs[*i] == Spring::Unknown)
+            .collect();
+        (0..1u32 << unknown.len())
+            .filter(|mask| {
+                let mut filled = springs.to_vec();
+                for (bit, i) in unknown.iter().enumerate() {
+                    filled[*i] = if mask >> bit & 1 == 1 {
+                        Spring::Damaged
+                    } else {
+                        Spring::Operational
+                    };
+                }
+                let runs: Vec<usize> = filled
+                    .split(|spring| *spring == Spring::Operational)
+                    .map(<[Spring]>::len)
+                    .filter(|len| *len > 0)
+                    .collect();
+                runs == groups
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_arrangements_match_brute_force() {
+        let input = springs(&mut Rng::new(7), 200);
+        let (_, rows) = parse(&input).expect("parsing failure");
+        for row in rows {
+            assert_eq!(
+                row.calculate_possibilities(),
+                brute_force(&row.springs, &row.groups),
+                "{row}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_rows_are_solvable() {
+        for seed in 0..20 {
+            let input = springs(&mut Rng::new(seed), 30);
+            let (_, rows) = parse(&input).expect("parsing failure");
+            assert_eq!(rows.len(), 30);
+            assert!(rows
+                .iter()
+                .all(|row| brute_force(&row.springs, &row.groups) > 0));
+        }
+    }
+}
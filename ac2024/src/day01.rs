@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     character::complete, combinator::map, multi::separated_list1, sequence::separated_pair, IResult,
 };
@@ -12,7 +13,7 @@ fn parse_file(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
     })(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (mut list1, mut list2)) = parse_file(input).expect("parsing failed");
     list1.sort_unstable();
     list2.sort_unstable();
@@ -21,20 +22,20 @@ pub fn part1(input: &str) -> String {
         .zip(list2)
         .map(|(x, y)| x.abs_diff(y))
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
 fn count<T: Eq>(x: &T, list: &[T]) -> usize {
     list.iter().filter(|item| *item == x).count()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (list1, list2)) = parse_file(input).expect("parsing failed");
     list1
         .into_iter()
         .map(|x| count(&x, &list2) * x as usize)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{character::complete, multi::separated_list1, IResult};
 
 fn parse(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
@@ -19,13 +20,9 @@ fn is_safe(report: &[u32]) -> bool {
     })
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, reports) = parse(input).expect("parsing failure");
-    reports
-        .into_iter()
-        .filter(|x| is_safe(x))
-        .count()
-        .to_string()
+    reports.into_iter().filter(|x| is_safe(x)).count().into()
 }
 
 fn is_safe_dampened(report: &[u32]) -> bool {
@@ -37,13 +34,13 @@ fn is_safe_dampened(report: &[u32]) -> bool {
         })
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, reports) = parse(input).expect("parsing failure");
     reports
         .into_iter()
         .filter(|x| is_safe_dampened(x))
         .count()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
@@ -46,7 +47,7 @@ fn parse(input: &str) -> IResult<&str, Vec<Operation>> {
     )(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, ops) = parse(input).expect("parsing error");
     ops.into_iter()
         .filter_map(|operation| match operation {
@@ -54,10 +55,10 @@ pub fn part1(input: &str) -> String {
             Operation::Do | Operation::Dont => None,
         })
         .sum::<u64>()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, ops) = parse(input).expect("parsing error");
     ops.into_iter()
         .fold((0, true), |(acc, state), val| match val {
@@ -66,7 +67,7 @@ pub fn part2(input: &str) -> String {
             Operation::Do => (acc, true),
         })
         .0
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
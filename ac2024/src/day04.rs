@@ -1,3 +1,5 @@
+use lib::Answer;
+
 fn horizontal(input: &[&str]) -> usize {
     input
         .iter()
@@ -49,10 +51,10 @@ fn diagonal(input: &[Vec<char>]) -> usize {
             .sum::<usize>()
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let data: Vec<&str> = input.lines().filter(|x| !x.is_empty()).collect();
     let as_vecs: Vec<Vec<char>> = data.iter().map(|x| x.chars().collect()).collect();
-    (horizontal(&data) + vertical(&as_vecs) + diagonal(&as_vecs)).to_string()
+    (horizontal(&data) + vertical(&as_vecs) + diagonal(&as_vecs)).into()
 }
 
 fn count_x_mas(input: &[Vec<char>]) -> usize {
@@ -70,10 +72,10 @@ fn count_x_mas(input: &[Vec<char>]) -> usize {
         .sum::<usize>()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let data: Vec<&str> = input.lines().filter(|x| !x.is_empty()).collect();
     let as_vecs: Vec<Vec<char>> = data.iter().map(|x| x.chars().collect()).collect();
-    count_x_mas(&as_vecs).to_string()
+    count_x_mas(&as_vecs).into()
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use lib::Answer;
 use nom::{
     character::complete,
     combinator::map,
@@ -31,14 +32,14 @@ fn parse_file(input: &str) -> IResult<&str, (Vec<Rule>, Vec<Vec<u64>>)> {
     )(input)
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (rules, updates)) = parse_file(input).expect("parsing error");
     updates
         .into_iter()
         .filter(|update| is_valid(&rules, update))
         .map(|update| update[update.len() / 2])
         .sum::<u64>()
-        .to_string()
+        .into()
 }
 
 fn is_valid(rules: &[Rule], update: &[u64]) -> bool {
@@ -56,7 +57,7 @@ fn is_valid(rules: &[Rule], update: &[u64]) -> bool {
     })
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (rules, updates)) = parse_file(input).expect("parsing error");
     updates
         .into_iter()
@@ -66,7 +67,7 @@ pub fn part2(input: &str) -> String {
             update[update.len() / 2]
         })
         .sum::<u64>()
-        .to_string()
+        .into()
 }
 
 fn fix_update(rules: &[Rule], update: &mut [u64]) {
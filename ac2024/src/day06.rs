@@ -3,6 +3,9 @@ use std::{
     collections::HashSet,
 };
 
+use lib::generate::Rng;
+use lib::grid::BitGrid;
+use lib::Answer;
 use nom::{
     branch::alt,
     character::complete,
@@ -124,7 +127,8 @@ fn find_wall(walls: &[Wall], guard: Guard) -> Option<Wall> {
     }
 }
 
-fn walk1(walls: &[Wall], guard: Guard) -> (bool, Guard) {
+/// Moves the guard to the next wall, or off the edge of a map `size` big.
+fn walk1(walls: &[Wall], guard: Guard, (width, height): (usize, usize)) -> (bool, Guard) {
     find_wall(walls, guard).map_or_else(
         || {
             (
@@ -132,22 +136,22 @@ fn walk1(walls: &[Wall], guard: Guard) -> (bool, Guard) {
                 match guard.direction {
                     Direction::Up => Guard {
                         x: guard.x,
-                        y: walls.iter().max_by_key(|wall| wall.y).expect("no walls").y,
+                        y: height - 1,
                         direction: guard.direction.next(),
                     },
                     Direction::Down => Guard {
                         x: guard.x,
-                        y: walls.iter().min_by_key(|wall| wall.y).expect("no walls").y,
+                        y: 1,
                         direction: guard.direction.next(),
                     },
                     Direction::Left => Guard {
                         y: guard.y,
-                        x: walls.iter().min_by_key(|wall| wall.x).expect("no walls").x,
+                        x: 2,
                         direction: guard.direction.next(),
                     },
                     Direction::Right => Guard {
                         y: guard.y,
-                        x: walls.iter().max_by_key(|wall| wall.x).expect("no walls").x,
+                        x: width - 1,
                         direction: guard.direction.next(),
                     },
                 },
@@ -189,72 +193,161 @@ enum WalkResult {
     Exit(Vec<Guard>),
 }
 
-fn walk(walls: &[Wall], mut guard: Guard) -> WalkResult {
+/// Lines count from 1 and columns are read just past each tile, so the map
+/// spans lines `1..height` and columns `2..width`.
+fn map_size(input: &str) -> (usize, usize) {
+    (
+        input.lines().map(str::len).max().unwrap_or(0) + 2,
+        input.lines().count() + 1,
+    )
+}
+
+fn walk(walls: &[Wall], mut guard: Guard, size: (usize, usize)) -> WalkResult {
+    let (width, height) = size;
+    let mut turns: [BitGrid; 4] = std::array::from_fn(|_| BitGrid::new(width, height));
     let mut path = vec![guard];
     loop {
-        let (hit_wall, next_guard) = walk1(walls, guard);
+        let (hit_wall, next_guard) = walk1(walls, guard, size);
         path.push(next_guard);
         if !hit_wall {
             return WalkResult::Exit(path);
         }
-        if path
-            .split_last()
-            .expect("empty path")
-            .1
-            .contains(&next_guard)
-        {
+        if !turns[next_guard.direction as usize].visit(next_guard.x, next_guard.y) {
             return WalkResult::Cycle;
         }
         guard = next_guard;
     }
 }
 
-fn expand_path(positions: &[Guard]) -> HashSet<(usize, usize)> {
-    positions
-        .windows(2)
-        .flat_map(|window| {
-            if window[0].x == window[1].x {
-                (min(window[0].y, window[1].y)..=max(window[0].y, window[1].y))
-                    .map(|y| (window[0].x, y))
-                    .collect::<Vec<_>>()
-            } else {
-                (min(window[0].x, window[1].x)..=max(window[0].x, window[1].x))
-                    .map(|x| (x, window[0].y))
-                    .collect::<Vec<_>>()
+fn expand_path(positions: &[Guard], (width, height): (usize, usize)) -> BitGrid {
+    let mut visited = BitGrid::new(width, height);
+    for window in positions.windows(2) {
+        if window[0].x == window[1].x {
+            for y in min(window[0].y, window[1].y)..=max(window[0].y, window[1].y) {
+                visited.set(window[0].x, y);
             }
-        })
-        .collect()
+        } else {
+            for x in min(window[0].x, window[1].x)..=max(window[0].x, window[1].x) {
+                visited.set(x, window[0].y);
+            }
+        }
+    }
+    visited
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, tiles) = parse_file(Span::new(input)).expect("parsing error");
     let (guard, walls) = extract(&tiles);
-    match walk(&walls, guard) {
+    let size = map_size(input);
+    match walk(&walls, guard, size) {
         WalkResult::Cycle => panic!("cycle in main path"),
-        WalkResult::Exit(path) => expand_path(&path).len().to_string(),
+        WalkResult::Exit(path) => expand_path(&path, size).count().into(),
     }
 }
 
-fn find_blockers(walls: &mut Vec<Wall>, guard: Guard) -> usize {
-    match walk(walls, guard) {
+fn find_blockers(walls: &mut Vec<Wall>, guard: Guard, size: (usize, usize)) -> usize {
+    match walk(walls, guard, size) {
         WalkResult::Cycle => panic!("cycle in main path"),
-        WalkResult::Exit(path) => expand_path(&path),
+        WalkResult::Exit(path) => expand_path(&path, size),
     }
-    .into_iter()
+    .iter()
     .map(|(x, y)| Wall { x, y })
     .filter(|wall| {
         walls.push(*wall);
-        let result = matches!(walk(walls, guard), WalkResult::Cycle);
+        let result = matches!(walk(walls, guard, size), WalkResult::Cycle);
         walls.pop();
         result
     })
     .count()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, tiles) = parse_file(Span::new(input)).expect("parsing error");
     let (guard, mut walls) = extract(&tiles);
-    find_blockers(&mut walls, guard).to_string()
+    find_blockers(&mut walls, guard, map_size(input)).into()
+}
+
+const TURNS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Walks the guard over a generated map, returning the visited cells or
+/// `None` if the guard never leaves.
+fn patrol(map: &[Vec<u8>], start: (usize, usize)) -> Option<HashSet<(usize, usize)>> {
+    let (mut x, mut y) = start;
+    let mut facing = 0;
+    let mut seen = HashSet::new();
+    let mut visited = HashSet::new();
+    while seen.insert((x, y, facing)) {
+        visited.insert((x, y));
+        let (dx, dy) = TURNS[facing];
+        let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+            return Some(visited);
+        };
+        match map.get(ny).and_then(|row| row.get(nx)) {
+            None => return Some(visited),
+            Some(b'#') => facing = (facing + 1) % 4,
+            Some(_) => (x, y) = (nx, ny),
+        }
+    }
+    None
+}
+
+/// Scatters walls away from the guard's path.
+fn finish_map(rng: &mut Rng, mut map: Vec<Vec<u8>>, start: (usize, usize), density: f64) -> String {
+    let visited = patrol(&map, start).expect("generated guard path loops");
+    for (y, row) in map.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            if !visited.contains(&(x, y)) && rng.chance(density) {
+                *tile = b'#';
+            }
+        }
+    }
+    map[start.1][start.0] = b'^';
+    map.into_iter()
+        .map(|row| String::from_utf8(row).expect("ascii map") + "\n")
+        .collect()
+}
+
+pub fn room(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    loop {
+        let mut map = vec![vec![b'.'; size]; size];
+        for tile in map.iter_mut().flatten() {
+            if rng.chance(0.08) {
+                *tile = b'#';
+            }
+        }
+        let start = (rng.range(1..size - 1), rng.range(1..size - 1));
+        map[start.1][start.0] = b'.';
+        if patrol(&map, start).is_some() {
+            return finish_map(rng, map, start, 0.0);
+        }
+    }
+}
+
+/// An inward spiral of walls, so the guard's path covers about half of the
+/// map before it leaves.
+pub fn spiral(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let inner = size - 2;
+    let mut map = vec![vec![b'.'; size]; size];
+    let start: (usize, usize) = (1, inner);
+    let (mut x, mut y) = start;
+    let mut facing = 0;
+    let mut legs = vec![inner - 1];
+    legs.extend(
+        (0..)
+            .map_while(|i| (inner - 1).checked_sub(2 * i))
+            .take_while(|leg| *leg > 0)
+            .flat_map(|leg| [leg, leg]),
+    );
+    for leg in legs {
+        let (dx, dy) = TURNS[facing];
+        x = x.wrapping_add_signed(dx * leg as isize);
+        y = y.wrapping_add_signed(dy * leg as isize);
+        map[y.wrapping_add_signed(dy)][x.wrapping_add_signed(dx)] = b'#';
+        facing = (facing + 1) % 4;
+    }
+    finish_map(rng, map, start, 0.05)
 }
 
 #[cfg(test)]
@@ -282,4 +375,31 @@ mod test {
     fn test_part2() {
         assert_eq!(part2(DATA1), 6.to_string());
     }
+
+    #[test]
+    fn test_path_past_walls() {
+        assert_eq!(part1("#..\n..^\n"), 2.to_string());
+        assert_eq!(part1("...\n.^.\n...\n"), 2.to_string());
+        assert_eq!(part2(".#..\n...#\n.^..\n..#.\n"), 1.to_string());
+    }
+
+    #[test]
+    fn test_generated_maps() {
+        for seed in 0..10 {
+            let mut rng = Rng::new(seed);
+            for input in [room(&mut rng, 30), spiral(&mut rng, 21)] {
+                let map: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+                let start = input
+                    .lines()
+                    .enumerate()
+                    .find_map(|(y, line)| line.find('^').map(|x| (x, y)))
+                    .unwrap();
+                let visited = patrol(&map, start).unwrap();
+                assert_eq!(part1(&input), visited.len().to_string());
+            }
+        }
+        for size in [5, 6, 20, 21] {
+            spiral(&mut Rng::new(0), size);
+        }
+    }
 }
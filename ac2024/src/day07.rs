@@ -1,4 +1,5 @@
 use itertools::{repeat_n, Itertools};
+use lib::Answer;
 use nom::{
     bytes::complete::tag, character::complete, combinator::map, multi::separated_list1,
     sequence::separated_pair, IResult,
@@ -57,24 +58,24 @@ fn validate(equation: &Equation, operator_options: &[Operator]) -> bool {
         .any(|operators| validate_one(equation, &operators))
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, equations) = parse_file(input).expect("parsing error");
     equations
         .into_iter()
         .filter(|eq| validate(eq, &[Operator::Add, Operator::Mul]))
         .map(|eq| eq.target)
         .sum::<u128>()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, equations) = parse_file(input).expect("parsing error");
     equations
         .into_iter()
         .filter(|eq| validate(eq, &[Operator::Add, Operator::Mul, Operator::Concat]))
         .map(|eq| eq.target)
         .sum::<u128>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
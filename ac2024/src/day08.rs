@@ -2,6 +2,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use itertools::Itertools;
+use lib::Answer;
 use nom::{
     branch::alt,
     character::complete,
@@ -82,7 +83,7 @@ fn antinodes_group(antennae: &[Antenna]) -> impl Iterator<Item = Antinode> + use
         .flat_map(|ae| antinodes_pair(*ae[0], *ae[1]))
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, antennae) = parse_file(Span::new(input)).expect("parsing failure");
     let max_x = input.lines().count() as isize - 1;
     let max_y = input.lines().next().expect("no lines").len() as isize - 1;
@@ -95,10 +96,10 @@ pub fn part1(input: &str) -> String {
         .filter(|an| an.x <= max_x && an.x >= min_x && an.y >= min_y && an.y <= max_y)
         .collect::<BTreeSet<Antinode>>()
         .len()
-        .to_string()
+        .into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, antennae) = parse_file(Span::new(input)).expect("parsing failure");
     let max_x = input.lines().count() as isize - 1;
     let max_y = input.lines().next().expect("no lines").len() as isize - 1;
@@ -110,7 +111,7 @@ pub fn part2(input: &str) -> String {
         .flat_map(|ae| antinodes_line_group(ae, min_x, max_x, min_y, max_y))
         .collect::<BTreeSet<Antinode>>()
         .len()
-        .to_string()
+        .into()
 }
 
 fn antinodes_line_group(
@@ -1,6 +1,8 @@
 use std::iter::repeat_n;
 
 use itertools::Itertools;
+use lib::generate::Rng;
+use lib::Answer;
 use nom::{
     character::complete,
     combinator::{map, map_opt, opt},
@@ -19,7 +21,7 @@ fn parse_pair(input: &str) -> IResult<&str, (usize, usize)> {
     pair(single_digit, single_digit)(input)
 }
 
-fn parse_input(input: &str) -> IResult<&str, (Vec<usize>, Vec<usize>)> {
+pub fn parse_input(input: &str) -> IResult<&str, (Vec<usize>, Vec<usize>)> {
     map(
         pair(many1(parse_pair), opt(single_digit)),
         |(pairs, maybe_file)| {
@@ -75,11 +77,11 @@ fn checksum(chunks: &[Option<FileChunk>]) -> usize {
         .sum()
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, (files, gaps)) = parse_input(input).expect("parsing error");
     let mut chunks = expand(&files, &gaps);
     compress_chunks(&mut chunks);
-    checksum(&chunks).to_string()
+    checksum(&chunks).into()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -155,7 +157,7 @@ fn checksum_files(files: &[File], gaps: &[usize]) -> usize {
         .sum()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, (files, mut gaps)) = parse_input(input).expect("parsing error");
     let mut wrapped_files = files
         .into_iter()
@@ -163,12 +165,46 @@ pub fn part2(input: &str) -> String {
         .map(|(id, size)| File::Unfrozen { id, size })
         .collect();
     compress_files(&mut wrapped_files, &mut gaps);
-    checksum_files(&wrapped_files, &gaps).to_string()
+    checksum_files(&wrapped_files, &gaps).into()
+}
+
+fn random_disk_map(
+    rng: &mut Rng,
+    size: usize,
+    files: (usize, usize),
+    gaps: (usize, usize),
+) -> String {
+    let mut map: String = (0..size.max(1))
+        .map(|i| {
+            let (low, high) = if i % 2 == 0 { files } else { gaps };
+            char::from_digit(rng.range(low..high + 1) as u32, 10).expect("digit")
+        })
+        .collect();
+    map.push('\n');
+    map
+}
+
+pub fn disk_map(rng: &mut Rng, size: usize) -> String {
+    random_disk_map(rng, size, (1, 9), (0, 9))
+}
+
+/// Small files separated by wide gaps, so compaction moves almost everything.
+pub fn fragmented_disk(rng: &mut Rng, size: usize) -> String {
+    random_disk_map(rng, size, (1, 3), (5, 9))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use lib::prop::{for_all, ints, vecs};
+
+    fn render(files: &[usize], gaps: &[usize]) -> String {
+        files
+            .iter()
+            .interleave(gaps)
+            .map(usize::to_string)
+            .collect()
+    }
 
     const DATA1: &str = r"2333133121414131402";
 
@@ -181,4 +217,49 @@ mod test {
     fn test_part2() {
         assert_eq!(part2(DATA1), 2858.to_string());
     }
+
+    #[test]
+    fn test_generated_part1() {
+        for seed in 0..10 {
+            let input = disk_map(&mut Rng::new(seed), 201);
+            let mut blocks: Vec<Option<usize>> = input
+                .trim()
+                .bytes()
+                .enumerate()
+                .flat_map(|(i, len)| {
+                    repeat_n((i % 2 == 0).then_some(i / 2), usize::from(len - b'0'))
+                })
+                .collect();
+            let (mut left, mut right) = (0, blocks.len() - 1);
+            while left < right {
+                if blocks[left].is_some() {
+                    left += 1;
+                } else if blocks[right].is_none() {
+                    right -= 1;
+                } else {
+                    blocks.swap(left, right);
+                }
+            }
+            let expected: usize = blocks
+                .iter()
+                .enumerate()
+                .filter_map(|(i, id)| id.map(|id| i * id))
+                .sum();
+            assert_eq!(part1(&input), expected.to_string());
+        }
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for_all(&vecs(ints(0..=9), 2..=40), |digits| {
+            let digits: Vec<usize> = digits.iter().map(|&digit| digit as usize).collect();
+            let files: Vec<usize> = digits.iter().step_by(2).copied().collect();
+            let gaps: Vec<usize> = digits.iter().skip(1).step_by(2).copied().collect();
+            let disk = render(&files, &gaps);
+            assert_eq!(parse_input(&disk), Ok(("", (files.clone(), gaps.clone()))));
+            let blocks = expand(&files, &gaps);
+            assert_eq!(blocks.len(), digits.iter().sum::<usize>());
+            assert_eq!(blocks.iter().flatten().count(), files.iter().sum::<usize>());
+        });
+    }
 }
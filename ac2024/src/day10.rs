@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use lib::Answer;
 use nom::{
     character::complete,
     combinator::{all_consuming, map_opt},
@@ -79,12 +80,9 @@ fn list_trailhead_endings(map: &[Vec<u8>], y: usize, x: usize) -> Vec<(usize, us
     total
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, map) = parse_input(input).expect("parsing error");
-    evaluate_trailheads(&map)
-        .into_iter()
-        .sum::<usize>()
-        .to_string()
+    evaluate_trailheads(&map).into_iter().sum::<usize>().into()
 }
 
 fn trailhead_ratings(map: &[Vec<u8>]) -> Vec<usize> {
@@ -140,12 +138,9 @@ fn count_trailheads(map: &[Vec<u8>], y: usize, x: usize) -> usize {
     total
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, map) = parse_input(input).expect("parsing error");
-    trailhead_ratings(&map)
-        .into_iter()
-        .sum::<usize>()
-        .to_string()
+    trailhead_ratings(&map).into_iter().sum::<usize>().into()
 }
 
 #[cfg(test)]
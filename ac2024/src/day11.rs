@@ -1,6 +1,7 @@
 #![allow(clippy::copy_iterator)]
 use std::collections::HashMap;
 
+use lib::Answer;
 use nom::{character::complete, multi::separated_list1, IResult};
 
 fn parse_stones(input: &str) -> IResult<&str, Vec<u64>> {
@@ -33,22 +34,22 @@ fn to_amounts(stones: &[u64]) -> HashMap<u64, u64> {
     amounts
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let (_, stones) = parse_stones(input).expect("parsing error");
     let mut stone_counts = to_amounts(&stones);
     for _ in 0..25 {
         stone_counts = update_stones(stone_counts);
     }
-    stone_counts.values().sum::<u64>().to_string()
+    stone_counts.values().sum::<u64>().into()
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let (_, stones) = parse_stones(input).expect("parsing error");
     let mut stone_counts = to_amounts(&stones);
     for _ in 0..75 {
         stone_counts = update_stones(stone_counts);
     }
-    stone_counts.values().sum::<u64>().to_string()
+    stone_counts.values().sum::<u64>().into()
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
-use std::collections::HashSet;
-
 use itertools::Itertools;
+use lib::grid::BitGrid;
+use lib::Answer;
 
 fn neighbours<T>(map: &[Vec<T>], x: usize, y: usize) -> [Option<(usize, usize)>; 4] {
     let mut out = [None; 4];
@@ -19,40 +19,37 @@ fn neighbours<T>(map: &[Vec<T>], x: usize, y: usize) -> [Option<(usize, usize)>;
     out
 }
 
-fn get_group(tiles: &[Vec<char>], x: usize, y: usize) -> HashSet<(usize, usize)> {
-    let mut out = HashSet::from([(x, y)]);
+/// Flood fills the region around `(x, y)`, marking it in `seen` as well.
+fn get_group(tiles: &[Vec<char>], x: usize, y: usize, seen: &mut BitGrid) -> Vec<(usize, usize)> {
+    seen.set(x, y);
+    let mut out = vec![(x, y)];
     let mut stack = vec![(x, y)];
 
     while let Some((x, y)) = stack.pop() {
         for (nx, ny) in neighbours(tiles, x, y).into_iter().flatten() {
-            if !out.contains(&(nx, ny)) && tiles[y][x] == tiles[ny][nx] {
+            if tiles[y][x] == tiles[ny][nx] && seen.visit(nx, ny) {
                 stack.push((nx, ny));
-                out.insert((nx, ny));
+                out.push((nx, ny));
             }
         }
     }
     out
 }
 
-fn group_tiles(tiles: &[Vec<char>]) -> Vec<HashSet<(usize, usize)>> {
-    let mut total: HashSet<(usize, usize)> = HashSet::new();
+fn group_tiles(tiles: &[Vec<char>]) -> Vec<Vec<(usize, usize)>> {
+    let mut seen = BitGrid::new(tiles[0].len(), tiles.len());
     let mut out = Vec::new();
     for (y, line) in tiles.iter().enumerate() {
         for (x, _) in line.iter().enumerate() {
-            if !total.contains(&(x, y)) {
-                let group = get_group(tiles, x, y);
-                total.extend(&group);
-                out.push(group);
+            if !seen.get(x, y) {
+                out.push(get_group(tiles, x, y, &mut seen));
             }
         }
     }
     out
 }
 
-fn perimeter(
-    map: &[Vec<char>],
-    group: &HashSet<(usize, usize)>,
-) -> Vec<((usize, usize), Direction)> {
+fn perimeter(map: &[Vec<char>], group: &[(usize, usize)]) -> Vec<((usize, usize), Direction)> {
     group
         .iter()
         .flat_map(|(x, y)| {
@@ -80,13 +77,13 @@ enum Direction {
     Right,
 }
 
-pub fn part1(input: &str) -> String {
+pub fn part1(input: &str) -> Answer {
     let tiles: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
     group_tiles(&tiles)
         .into_iter()
         .map(|group| perimeter(&tiles, &group).len() * group.len())
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 fn same_line(
@@ -118,13 +115,13 @@ fn reduce_fences(
     out
 }
 
-pub fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> Answer {
     let tiles: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
     group_tiles(&tiles)
         .into_iter()
         .map(|group| reduce_fences(perimeter(&tiles, &group)).len() * group.len())
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 #[cfg(test)]
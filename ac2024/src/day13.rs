@@ -0,0 +1,287 @@
+use lib::Answer;
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Button {
+    x: u64,
+    y: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Prize {
+    x: u64,
+    y: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Machine {
+    button_a: Button,
+    button_b: Button,
+    prize: Prize,
+}
+
+fn parse_button(input: &str) -> IResult<&str, Button> {
+    terminated(
+        map(
+            preceded(
+                separated_pair(tag("Button "), complete::one_of("AB"), tag(": X+")),
+                separated_pair(complete::u64, tag(", Y+"), complete::u64),
+            ),
+            |(x, y)| Button { x, y },
+        ),
+        complete::newline,
+    )(input)
+}
+
+fn parse_prize(input: &str) -> IResult<&str, Prize> {
+    terminated(
+        map(
+            preceded(
+                tag("Prize: X="),
+                separated_pair(complete::u64, tag(", Y="), complete::u64),
+            ),
+            |(x, y)| Prize { x, y },
+        ),
+        complete::newline,
+    )(input)
+}
+
+fn parse_machine(input: &str) -> IResult<&str, Machine> {
+    map(
+        tuple((parse_button, parse_button, parse_prize)),
+        |(button_a, button_b, prize)| Machine {
+            button_a,
+            button_b,
+            prize,
+        },
+    )(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Machine>> {
+    separated_list1(complete::newline, parse_machine)(input)
+}
+
+fn get_optimal_path(machine: Machine) -> Option<(u64, u64)> {
+    let Machine {
+        button_a,
+        button_b,
+        prize,
+    } = machine;
+    let mut out = None;
+    for a_count in 0.. {
+        if a_count * button_a.x > prize.x || a_count * button_a.y > prize.y {
+            break;
+        }
+        let remaining_x = prize.x - a_count * button_a.x;
+        let remaining_y = prize.y - a_count * button_a.y;
+        if !(remaining_x % button_b.x == 0
+            && remaining_y % button_b.y == 0
+            && remaining_x / button_b.x == remaining_y / button_b.y)
+        {
+            continue;
+        }
+        let b_count = remaining_x / button_b.x;
+        if let Some((a_old, b_old)) = out {
+            if a_count * 3 + b_count < a_old * 3 + b_old {
+                out = Some((a_count, b_count));
+            }
+        } else {
+            out = Some((a_count, b_count));
+        }
+    }
+    out
+}
+
+/// Returns `(g, s, t)` with `a * s + b * t == g == gcd(a, b)`.
+const fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, s, t) = extended_gcd(b, a % b);
+        (g, t, s - a / b * t)
+    }
+}
+
+/// The cheapest non-negative `(a, b)` with `a * a_step + b * b_step ==
+/// target`, for buttons that move along the same line as the prize.
+fn cheapest_on_line(a_step: i128, b_step: i128, target: i128) -> Option<(i128, i128)> {
+    match (a_step, b_step) {
+        (0, 0) => (target == 0).then_some((0, 0)),
+        (0, _) => (target % b_step == 0).then_some((0, target / b_step)),
+        (_, 0) => (target % a_step == 0).then_some((target / a_step, 0)),
+        _ => {
+            let (g, s, t) = extended_gcd(a_step, b_step);
+            if target % g != 0 {
+                return None;
+            }
+            // Every solution is (a0 + k * da, b0 - k * db), with a cost that
+            // changes linearly in k, so the cheapest sits at one end.
+            let (a0, b0) = (s * (target / g), t * (target / g));
+            let (da, db) = (b_step / g, a_step / g);
+            let k_min = (-a0).div_euclid(da) + i128::from((-a0).rem_euclid(da) != 0);
+            let k_max = b0.div_euclid(db);
+            if k_min > k_max {
+                return None;
+            }
+            let k = if 3 * da > db { k_min } else { k_max };
+            Some((a0 + k * da, b0 - k * db))
+        }
+    }
+}
+
+/// Solves the two-equation system for the button presses directly, falling
+/// back to a one-dimensional search when both buttons are collinear.
+fn get_cheapest_presses(machine: Machine) -> Option<(u64, u64)> {
+    let (ax, ay) = (
+        i128::from(machine.button_a.x),
+        i128::from(machine.button_a.y),
+    );
+    let (bx, by) = (
+        i128::from(machine.button_b.x),
+        i128::from(machine.button_b.y),
+    );
+    let (px, py) = (i128::from(machine.prize.x), i128::from(machine.prize.y));
+    let determinant = ax * by - ay * bx;
+    let (a, b) = if determinant == 0 {
+        if ax * py != ay * px || bx * py != by * px {
+            return None;
+        }
+        if ax == 0 && bx == 0 {
+            cheapest_on_line(ay, by, py)?
+        } else {
+            cheapest_on_line(ax, bx, px)?
+        }
+    } else {
+        let a = px * by - py * bx;
+        let b = ax * py - ay * px;
+        if a % determinant != 0 || b % determinant != 0 {
+            return None;
+        }
+        (a / determinant, b / determinant)
+    };
+    if a * ax + b * bx != px || a * ay + b * by != py {
+        return None;
+    }
+    Some((u64::try_from(a).ok()?, u64::try_from(b).ok()?))
+}
+
+fn total_cost(machines: Vec<Machine>, presses: impl Fn(Machine) -> Option<(u64, u64)>) -> Answer {
+    machines
+        .into_iter()
+        .filter_map(presses)
+        .map(|(r#as, bs)| r#as * 3 + bs)
+        .sum::<u64>()
+        .into()
+}
+
+fn far_away(machine: Machine) -> Machine {
+    let Prize { x, y } = machine.prize;
+    Machine {
+        prize: Prize {
+            x: x + 10_000_000_000_000,
+            y: y + 10_000_000_000_000,
+        },
+        ..machine
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    total_cost(
+        parse_input(input).expect("parsing error").1,
+        get_cheapest_presses,
+    )
+}
+
+pub fn part1_brute_force(input: &str) -> Answer {
+    total_cost(
+        parse_input(input).expect("parsing error").1,
+        get_optimal_path,
+    )
+}
+
+pub fn part2(input: &str) -> Answer {
+    let machines = parse_input(input).expect("parsing error").1;
+    total_cost(
+        machines.into_iter().map(far_away).collect(),
+        get_cheapest_presses,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DATA1: &str = r"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_part1_1() {
+        assert_eq!(part1(DATA1), 480.to_string());
+    }
+
+    #[test]
+    fn test_part1_variants_agree() {
+        assert_eq!(part1_brute_force(DATA1), part1(DATA1));
+        let input = include_str!("../input/p13");
+        assert_eq!(part1_brute_force(input), part1(input));
+    }
+
+    #[test]
+    fn test_part2_1() {
+        assert_eq!(part2(DATA1), 875_318_608_908_u64.to_string());
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let machine = |a: (u64, u64), b: (u64, u64), prize: (u64, u64)| Machine {
+            button_a: Button { x: a.0, y: a.1 },
+            button_b: Button { x: b.0, y: b.1 },
+            prize: Prize {
+                x: prize.0,
+                y: prize.1,
+            },
+        };
+        for machine in [
+            machine((2, 2), (1, 1), (10, 10)),
+            machine((4, 4), (1, 1), (8, 8)),
+            machine((6, 3), (4, 2), (10, 5)),
+            machine((6, 3), (4, 2), (14, 7)),
+            machine((6, 3), (4, 2), (7, 5)),
+            machine((6, 6), (4, 4), (7, 7)),
+            machine((9, 3), (15, 5), (48, 16)),
+            machine((1, 2), (2, 4), (100, 200)),
+        ] {
+            let cost = |presses: Option<(u64, u64)>| presses.map(|(a, b)| 3 * a + b);
+            assert_eq!(
+                cost(get_cheapest_presses(machine)),
+                cost(get_optimal_path(machine)),
+                "{machine:?}"
+            );
+        }
+        assert_eq!(
+            get_cheapest_presses(machine((4, 4), (1, 1), (8, 8))),
+            Some((2, 0))
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use lib::image::{Image, BLACK};
+use lib::viz::{Frame, Simulation};
+use lib::Answer;
 use nom::{
     bytes::complete::tag,
     character::complete,
@@ -109,18 +112,18 @@ fn safety_factor(robots: &[Robot], width: i64, height: i64) -> i64 {
     q1 * q2 * q3 * q4
 }
 
-fn simulate_robots(input: &str, width: i64, height: i64) -> String {
+fn simulate_robots(input: &str, width: i64, height: i64) -> i64 {
     let (_, mut robots) = parse_input(input).expect("parsing error");
     for _ in 0..100 {
         for robot in &mut robots {
             robot.update(width, height);
         }
     }
-    safety_factor(&robots, width, height).to_string()
+    safety_factor(&robots, width, height)
 }
 
-pub fn part1(input: &str) -> String {
-    simulate_robots(input, 101, 103)
+pub fn part1(input: &str) -> Answer {
+    simulate_robots(input, 101, 103).into()
 }
 
 fn possible_tree(robots: &[Robot]) -> bool {
@@ -136,44 +139,61 @@ fn possible_tree(robots: &[Robot]) -> bool {
         >= 250
 }
 
-fn _display(robots: &[Robot]) {
-    let max_y = robots
-        .iter()
-        .map(|robot| robot.position.y)
-        .max()
-        .expect("no robots");
-    let max_x = robots
-        .iter()
-        .map(|robot| robot.position.x)
-        .max()
-        .expect("no robots");
-    for y in 0..=max_y {
-        println!(
-            "{}",
-            (0..=max_x)
-                .map(
-                    |x| if robots.iter().any(|robot| robot.position == Vec2::new(x, y)) {
-                        '*'
-                    } else {
-                        '.'
-                    }
-                )
-                .collect::<String>()
-        );
-    }
+pub struct Lobby {
+    robots: Vec<Robot>,
+    width: i64,
+    height: i64,
 }
 
-pub fn part2(input: &str) -> String {
-    let (_, mut robots) = parse_input(input).expect("parsing error");
-    for i in 1.. {
-        for robot in &mut robots {
-            robot.update(101, 103);
+impl Simulation for Lobby {
+    fn frame(&self) -> Frame {
+        let mut rows = vec![vec!['.'; self.width as usize]; self.height as usize];
+        for robot in &self.robots {
+            rows[robot.position.y as usize][robot.position.x as usize] = '#';
+        }
+        rows.into_iter().map(String::from_iter).collect()
+    }
+
+    fn step(&mut self) -> bool {
+        if possible_tree(&self.robots) {
+            return false;
         }
-        if possible_tree(&robots) {
-            return i.to_string();
+        for robot in &mut self.robots {
+            robot.update(self.width, self.height);
         }
+        true
+    }
+}
+
+pub fn lobby(input: &str) -> Lobby {
+    let (_, robots) = parse_input(input).expect("parsing error");
+    Lobby {
+        robots,
+        width: 101,
+        height: 103,
+    }
+}
+
+pub fn tree(input: &str) -> Image {
+    let mut lobby = lobby(input);
+    while lobby.step() {}
+    Image::from_points(
+        lobby
+            .robots
+            .iter()
+            .map(|robot| ((robot.position.x, robot.position.y), ())),
+        BLACK,
+        |()| [60, 200, 90],
+    )
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut lobby = lobby(input);
+    let mut seconds = 0;
+    while lobby.step() {
+        seconds += 1;
     }
-    unreachable!()
+    seconds.into()
 }
 
 #[cfg(test)]
@@ -196,6 +216,6 @@ p=9,5 v=-3,-3
 
     #[test]
     fn test_part1() {
-        assert_eq!(simulate_robots(DATA1, 11, 7), 12.to_string());
+        assert_eq!(simulate_robots(DATA1, 11, 7), 12);
     }
 }
@@ -1,4 +1,5 @@
-#![allow(clippy::match_on_vec_items)]
+use lib::viz::{Frame, Simulation};
+use lib::Answer;
 use nom::{
     branch::alt,
     character::complete,
@@ -149,7 +150,6 @@ fn do_move(map: &mut [Vec<Tile>], x: usize, y: usize, direction: Move) {
 }
 
 fn try_move(map: &mut [Vec<Tile>], x: usize, y: usize, direction: Move) -> bool {
-    #![allow(clippy::match_on_vec_items)]
     let (other_x, other_y) = get_obstacle(x, y, direction);
     if can_move(map, other_x, other_y, direction) {
         do_move(map, other_x, other_y, direction);
@@ -181,39 +181,15 @@ fn get_gps(map: &[Vec<Tile>]) -> usize {
         .sum::<usize>()
 }
 
-fn _display(map: &[Vec<Tile>]) {
-    for line in map {
-        println!(
-            "{}",
-            line.iter()
-                .map(|x| match x {
-                    Tile::Empty => '.',
-                    Tile::Wall => '#',
-                    Tile::Box => 'O',
-                    Tile::Robot => '@',
-                    Tile::BoxL => '[',
-                    Tile::BoxR => ']',
-                })
-                .collect::<String>()
-        );
-    }
-}
-
-pub fn part1(input: &str) -> String {
-    let (_, (mut map, moves)) = parse_input(input).expect("parsing error");
-    let mut robot = find_robot(&map).expect("no robot");
-
-    for direction in moves {
-        if try_move(&mut map, robot.x, robot.y, direction) {
-            match direction {
-                Move::Up => robot.y -= 1,
-                Move::Down => robot.y += 1,
-                Move::Left => robot.x -= 1,
-                Move::Right => robot.x += 1,
-            }
-        }
+const fn tile_char(tile: Tile) -> char {
+    match tile {
+        Tile::Empty => '.',
+        Tile::Wall => '#',
+        Tile::Box => 'O',
+        Tile::Robot => '@',
+        Tile::BoxL => '[',
+        Tile::BoxR => ']',
     }
-    get_gps(&map).to_string()
 }
 
 fn widen(map: &[Vec<Tile>]) -> Vec<Vec<Tile>> {
@@ -233,12 +209,49 @@ fn widen(map: &[Vec<Tile>]) -> Vec<Vec<Tile>> {
         .collect()
 }
 
-pub fn part2(input: &str) -> String {
-    let (_, (mut map, moves)) = parse_input(input).expect("parsing error");
-    map = widen(&map);
-    let mut robot = find_robot(&map).expect("no robot");
-    for direction in moves {
-        if try_move(&mut map, robot.x, robot.y, direction) {
+pub struct Warehouse {
+    map: Vec<Vec<Tile>>,
+    robot: Robot,
+    moves: Vec<Move>,
+    index: usize,
+}
+
+impl Warehouse {
+    fn new(input: &str, wide: bool) -> Self {
+        let (_, (mut map, moves)) = parse_input(input).expect("parsing error");
+        if wide {
+            map = widen(&map);
+        }
+        let robot = find_robot(&map).expect("no robot");
+        Self {
+            map,
+            robot,
+            moves,
+            index: 0,
+        }
+    }
+
+    fn run(mut self) -> usize {
+        while self.step() {}
+        get_gps(&self.map)
+    }
+}
+
+impl Simulation for Warehouse {
+    fn frame(&self) -> Frame {
+        self.map
+            .iter()
+            .map(|line| line.iter().copied().map(tile_char).collect())
+            .collect()
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&direction) = self.moves.get(self.index) else {
+            return false;
+        };
+        self.index += 1;
+        let robot = &mut self.robot;
+        if try_move(&mut self.map, robot.x, robot.y, direction) {
             match direction {
                 Move::Up => robot.y -= 1,
                 Move::Down => robot.y += 1,
@@ -246,8 +259,42 @@ pub fn part2(input: &str) -> String {
                 Move::Right => robot.x += 1,
             }
         }
+        true
     }
-    get_gps(&map).to_string()
+
+    fn next_step(&self) -> Option<String> {
+        let direction = self.moves.get(self.index)?;
+        Some(format!(
+            "move {direction:?} ({} of {})",
+            self.index + 1,
+            self.moves.len()
+        ))
+    }
+
+    fn watches(&self) -> Vec<(&'static str, i64)> {
+        vec![
+            ("x", self.robot.x as i64),
+            ("y", self.robot.y as i64),
+            ("move", self.index as i64),
+            ("gps", get_gps(&self.map) as i64),
+        ]
+    }
+}
+
+pub fn warehouse(input: &str) -> Warehouse {
+    Warehouse::new(input, false)
+}
+
+pub fn wide_warehouse(input: &str) -> Warehouse {
+    Warehouse::new(input, true)
+}
+
+pub fn part1(input: &str) -> Answer {
+    warehouse(input).run().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    wide_warehouse(input).run().into()
 }
 
 #[cfg(test)]
@@ -303,4 +350,12 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
     fn test_part2() {
         assert_eq!(part2(DATA1), 9021.to_string());
     }
+
+    #[test]
+    fn test_warehouse_frames() {
+        let mut warehouse = wide_warehouse(DATA2);
+        assert_eq!(warehouse.frame()[2], "####@...[]....##");
+        while warehouse.step() {}
+        assert_eq!(warehouse.frame()[1], "##......[][]..##");
+    }
 }
@@ -0,0 +1,73 @@
+use lib::generate::Generator;
+use lib::image::Picture;
+use lib::viz::Animation;
+use lib::{animation, generator, picture, solution, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(01, 1),
+    solution!(01, 2),
+    solution!(02, 1),
+    solution!(02, 2),
+    solution!(03, 1),
+    solution!(03, 2),
+    solution!(04, 1),
+    solution!(04, 2),
+    solution!(05, 1),
+    solution!(05, 2),
+    solution!(06, 1),
+    solution!(06, 2),
+    solution!(07, 1),
+    solution!(07, 2),
+    solution!(08, 1),
+    solution!(08, 2),
+    solution!(09, 1),
+    solution!(09, 2),
+    solution!(10, 1),
+    solution!(10, 2),
+    solution!(11, 1),
+    solution!(11, 2),
+    solution!(12, 1),
+    solution!(12, 2),
+    solution!(13, 1),
+    solution!(13, 1, brute_force),
+    solution!(13, 2),
+    solution!(14, 1),
+    solution!(14, 2),
+    solution!(15, 1),
+    solution!(15, 2),
+];
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const ANIMATIONS: &[Animation] = &[
+    animation!(14, lobby),
+    animation!(15, warehouse),
+    animation!(15, wide_warehouse),
+];
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const PICTURES: &[Picture] = &[picture!(14, tree)];
+
+#[allow(clippy::zero_prefixed_literal)]
+pub const GENERATORS: &[Generator] = &[
+    generator!(06, room),
+    generator!(06, spiral),
+    generator!(09, disk_map),
+    generator!(09, fragmented_disk),
+];
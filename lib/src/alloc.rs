@@ -0,0 +1,123 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// Counted per thread, so a measurement only sees its own thread's
+// allocations. Memory freed on another thread than it was allocated on can
+// take `CURRENT` below zero.
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(size: usize) {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        ALLOCATED.with(|allocated| allocated.set(allocated.get() + size));
+        let current = CURRENT.with(|current| {
+            current.set(current.get().wrapping_add_unsigned(size));
+            current.get()
+        });
+        PEAK.with(|peak| peak.set(peak.get().max(current)));
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.with(|current| current.set(current.get().wrapping_sub_unsigned(size)));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::shrink(layout.size());
+            Self::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub peak: usize,
+    pub allocations: usize,
+    pub allocated: usize,
+}
+
+pub const fn enabled() -> bool {
+    cfg!(feature = "count-alloc")
+}
+
+/// Runs `f`, counting the allocations it makes on the current thread.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, AllocStats) {
+    let base = CURRENT.with(Cell::get);
+    PEAK.with(|peak| peak.set(base));
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let allocated = ALLOCATED.with(Cell::get);
+    let result = f();
+    let stats = AllocStats {
+        peak: PEAK.with(Cell::get).abs_diff(base),
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+        allocated: ALLOCATED.with(Cell::get) - allocated,
+    };
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let bytes = bytes as f64;
+    if bytes >= 1024.0 * 1024.0 * 1024.0 {
+        format!("{:.2}GiB", bytes / (1024.0 * 1024.0 * 1024.0))
+    } else if bytes >= 1024.0 * 1024.0 {
+        format!("{:.2}MiB", bytes / (1024.0 * 1024.0))
+    } else if bytes >= 1024.0 {
+        format!("{:.2}KiB", bytes / 1024.0)
+    } else {
+        format!("{bytes}B")
+    }
+}
+
+#[cfg(all(test, feature = "count-alloc"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let first = std::hint::black_box(vec![0u8; 1000]);
+            drop(first);
+            std::hint::black_box(vec![0u8; 600])
+        });
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.allocated, 1600);
+        assert_eq!(stats.peak, 1000);
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::ocr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Bitmap(Vec<Vec<bool>>),
+}
+
+macro_rules! from_integer {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Integer(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or_else(|_| Self::Text(value.to_string()), Self::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(value: Vec<Vec<bool>>) -> Self {
+        Self::Bitmap(value)
+    }
+}
+
+fn render_row(row: &[bool], on: char, off: char) -> String {
+    row.iter().map(|lit| if *lit { on } else { off }).collect()
+}
+
+fn parse_art(art: &str) -> Vec<Vec<bool>> {
+    art.lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect()
+}
+
+fn normalise(bitmap: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    let mut rows: Vec<Vec<bool>> = bitmap
+        .iter()
+        .map(|row| {
+            let len = row.iter().rposition(|lit| *lit).map_or(0, |i| i + 1);
+            row[..len].to_vec()
+        })
+        .collect();
+    while rows.last().is_some_and(Vec::is_empty) {
+        rows.pop();
+    }
+    let leading = rows.iter().take_while(|row| row.is_empty()).count();
+    rows.split_off(leading)
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl Answer {
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Integer(_) => "integer",
+            Self::Text(_) => "text",
+            Self::Bitmap(_) => "bitmap",
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Self::Integer(_) => false,
+            Self::Text(text) => text.contains('\n'),
+            Self::Bitmap(_) => true,
+        }
+    }
+
+    pub fn letters(&self) -> Option<String> {
+        match self {
+            Self::Bitmap(bitmap) => ocr::decode(bitmap).ok(),
+            Self::Integer(_) | Self::Text(_) => None,
+        }
+    }
+
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Integer(value) => expected.trim().parse() == Ok(*value),
+            Self::Text(text) => text == expected,
+            Self::Bitmap(bitmap) => {
+                normalise(bitmap) == normalise(&parse_art(expected))
+                    || self
+                        .letters()
+                        .is_some_and(|letters| letters == expected.trim())
+            }
+        }
+    }
+
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Self::Integer(value) => Some(value.to_string()),
+            Self::Text(text) if !text.is_empty() && !text.contains('\n') => Some(text.clone()),
+            Self::Bitmap(_) => self.letters(),
+            Self::Text(_) => None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let value = match self {
+            Self::Integer(value) => value.to_string(),
+            Self::Text(text) => json_string(text),
+            Self::Bitmap(bitmap) => format!(
+                "[{}],\"letters\":{}",
+                bitmap
+                    .iter()
+                    .map(|row| json_string(&render_row(row, '#', '.')))
+                    .collect::<Vec<_>>()
+                    .join(","),
+                self.letters()
+                    .map_or_else(|| "null".to_string(), |letters| json_string(&letters))
+            ),
+        };
+        format!("{{\"kind\":\"{}\",\"value\":{value}}}", self.kind())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Bitmap(bitmap) => write!(
+                f,
+                "{}",
+                bitmap
+                    .iter()
+                    .map(|row| render_row(row, '#', ' '))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self.matches(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_numbers() {
+        assert_eq!(Answer::from(5u8), Answer::Integer(5));
+        assert_eq!(Answer::from(-5i64), Answer::Integer(-5));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_matches() {
+        assert_eq!(Answer::from(1588u64), "1588");
+        assert_eq!(Answer::from(1588u64), "1588\n");
+        assert_eq!(Answer::from("abc"), "abc");
+        let bitmap = Answer::Bitmap(vec![vec![true, false, true], vec![false, true, false]]);
+        assert_eq!(bitmap, "\n# #\n # \n");
+        assert_eq!(bitmap, "#.#\n.#.");
+        assert_ne!(bitmap, "###\n.#.");
+        let letters = Answer::Bitmap(
+            "#..#\n#..#\n####\n#..#\n#..#\n#..#"
+                .lines()
+                .map(|line| line.chars().map(|c| c == '#').collect())
+                .collect(),
+        );
+        assert_eq!(letters, "H");
+        assert_eq!(letters.submission(), Some("H".to_string()));
+    }
+
+    #[test]
+    fn test_output() {
+        let bitmap = Answer::Bitmap(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(bitmap.to_string(), "# \n #");
+        assert_eq!(
+            bitmap.to_json(),
+            r##"{"kind":"bitmap","value":["#.",".#"],"letters":null}"##
+        );
+        assert_eq!(bitmap.submission(), None);
+        assert_eq!(
+            Answer::from("a\"b").to_json(),
+            r#"{"kind":"text","value":"a\"b"}"#
+        );
+        assert_eq!(Answer::from(12u32).submission(), Some("12".to_string()));
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+
+use crate::grid::{BitGrid, Cells, Coord, Grid};
+
+/// What lies past the edge of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Nothing: cells on the edge have fewer neighbours.
+    Bounded,
+    /// The opposite edge, so the grid is a torus.
+    Wrapping,
+}
+
+/// Rules for a step where cells can set each other off. Every cell is
+/// started once; each cell that fires excites its neighbours, which may fire
+/// in turn. A cell fires at most once per step and ignores excitement after
+/// that, and every cell that fired is settled at the end.
+pub trait Cascade<T> {
+    /// Returns whether the cell fires.
+    fn start(&self, cell: &mut T) -> bool;
+
+    /// A neighbour fired; returns whether the cell fires.
+    fn excite(&self, cell: &mut T) -> bool;
+
+    fn settle(&self, _cell: &mut T) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// How many steps have run, including this one.
+    pub generation: usize,
+    /// Cells holding a different value than before the step.
+    pub changed: usize,
+    /// Cells that fired during a cascade; always zero for synchronous steps.
+    pub fired: usize,
+}
+
+/// A grid of cells that evolve step by step, each looking at the cells at
+/// the offsets in `neighbourhood`.
+#[derive(Debug, Clone)]
+pub struct Automaton<'a, T> {
+    pub grid: Grid<T>,
+    neighbourhood: &'a [Coord],
+    edges: Edges,
+    generation: usize,
+}
+
+impl<'a, T: Clone + PartialEq> Automaton<'a, T> {
+    pub const fn new(grid: Grid<T>, neighbourhood: &'a [Coord], edges: Edges) -> Self {
+        Self {
+            grid,
+            neighbourhood,
+            edges,
+            generation: 0,
+        }
+    }
+
+    pub const fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn neighbours(&self, (x, y): Coord) -> impl Iterator<Item = Coord> + '_ {
+        let (width, height) = (self.grid.width() as i64, self.grid.height() as i64);
+        self.neighbourhood
+            .iter()
+            .filter_map(move |(dx, dy)| match self.edges {
+                Edges::Bounded => {
+                    let at = (x + dx, y + dy);
+                    self.grid.get(at).is_some().then_some(at)
+                }
+                Edges::Wrapping => Some(((x + dx).rem_euclid(width), (y + dy).rem_euclid(height))),
+            })
+    }
+
+    fn stats(&mut self, before: &Grid<T>, fired: usize) -> Stats {
+        self.generation += 1;
+        Stats {
+            generation: self.generation,
+            changed: self
+                .grid
+                .iter()
+                .filter(|(at, value)| before[*at] != **value)
+                .count(),
+            fired,
+        }
+    }
+
+    /// Replaces every cell at once with `rule` applied to its current value
+    /// and those of its neighbours.
+    pub fn step(&mut self, rule: impl Fn(&T, &[&T]) -> T) -> Stats {
+        let mut next = self.grid.clone();
+        for (at, value) in self.grid.iter() {
+            let neighbours: Vec<&T> = self.neighbours(at).map(|at| &self.grid[at]).collect();
+            next[at] = rule(value, &neighbours);
+        }
+        let before = std::mem::replace(&mut self.grid, next);
+        self.stats(&before, 0)
+    }
+
+    /// Runs one step of a cascade, spreading from the cells that fire at the
+    /// start in the order they fired.
+    pub fn cascade(&mut self, rules: &impl Cascade<T>) -> Stats {
+        let before = self.grid.clone();
+        let mut fired = BitGrid::new(self.grid.width(), self.grid.height());
+        let mut queue = VecDeque::new();
+        let coords: Vec<Coord> = self.grid.iter().map(|(at, _)| at).collect();
+        for at in coords {
+            if rules.start(&mut self.grid[at]) {
+                fired.set(at.0 as usize, at.1 as usize);
+                queue.push_back(at);
+            }
+        }
+        while let Some(at) = queue.pop_front() {
+            let neighbours: Vec<Coord> = self.neighbours(at).collect();
+            for next in neighbours {
+                if !fired.get(next.0 as usize, next.1 as usize)
+                    && rules.excite(&mut self.grid[next])
+                {
+                    fired.set(next.0 as usize, next.1 as usize);
+                    queue.push_back(next);
+                }
+            }
+        }
+        for (x, y) in fired.iter() {
+            rules.settle(&mut self.grid[(x as i64, y as i64)]);
+        }
+        self.stats(&before, fired.count())
+    }
+
+    /// Runs `step` until `done` holds after it, giving up after `limit`
+    /// steps if there is one.
+    pub fn run_until(
+        &mut self,
+        mut step: impl FnMut(&mut Self) -> Stats,
+        done: impl Fn(&Self, &Stats) -> bool,
+        limit: Option<usize>,
+    ) -> Option<Stats> {
+        let mut steps = 0;
+        while limit.is_none_or(|limit| steps < limit) {
+            steps += 1;
+            let stats = step(self);
+            if done(self, &stats) {
+                return Some(stats);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::{ADJACENT, ORTHOGONAL};
+
+    fn life(alive: &bool, neighbours: &[&bool]) -> bool {
+        let count = neighbours.iter().filter(|alive| ***alive).count();
+        count == 3 || (*alive && count == 2)
+    }
+
+    fn render(automaton: &Automaton<bool>) -> Vec<String> {
+        automaton
+            .grid
+            .render(|alive| if alive == Some(&true) { '#' } else { '.' })
+    }
+
+    #[test]
+    fn test_synchronous() {
+        let blinker = Grid::parse(".....\n..#..\n..#..\n..#..\n.....", |c| c == '#');
+        let mut automaton = Automaton::new(blinker.clone(), &ADJACENT, Edges::Bounded);
+        let stats = automaton.step(life);
+        assert_eq!(
+            render(&automaton),
+            [".....", ".....", ".###.", ".....", "....."]
+        );
+        assert_eq!(
+            stats,
+            Stats {
+                generation: 1,
+                changed: 4,
+                fired: 0
+            }
+        );
+        automaton.step(life);
+        assert_eq!(automaton.grid, blinker);
+    }
+
+    #[test]
+    fn test_edges() {
+        let glider = Grid::parse(".#....\n..#...\n###...\n......\n......\n......", |c| {
+            c == '#'
+        });
+        let mut wrapping = Automaton::new(glider.clone(), &ADJACENT, Edges::Wrapping);
+        let back = wrapping.run_until(
+            |automaton| automaton.step(life),
+            |automaton, _| automaton.grid == glider,
+            Some(100),
+        );
+        assert_eq!(back.map(|stats| stats.generation), Some(24));
+        let mut bounded = Automaton::new(glider, &ADJACENT, Edges::Bounded);
+        let still = bounded.run_until(
+            |automaton| automaton.step(life),
+            |_, stats| stats.changed == 0,
+            Some(100),
+        );
+        assert_eq!(still.map(|stats| stats.generation), Some(16));
+        assert_eq!(render(&bounded)[4..], ["....##", "....##"]);
+        let moved = bounded.run_until(
+            |automaton| automaton.step(life),
+            |_, stats| stats.changed > 0,
+            Some(3),
+        );
+        assert_eq!((moved, bounded.generation()), (None, 19));
+    }
+
+    struct Spread;
+
+    impl Cascade<u8> for Spread {
+        fn start(&self, cell: &mut u8) -> bool {
+            *cell == 1
+        }
+
+        fn excite(&self, cell: &mut u8) -> bool {
+            *cell = 2;
+            true
+        }
+    }
+
+    #[test]
+    fn test_cascade() {
+        let grid = Grid::parse("0000\n0100\n0000", |c| c.to_digit(10).unwrap() as u8);
+        let mut automaton = Automaton::new(grid, &ORTHOGONAL, Edges::Bounded);
+        let stats = automaton.cascade(&Spread);
+        assert_eq!((stats.fired, stats.changed), (12, 11));
+        let neighbours: Vec<Coord> = automaton.neighbours((0, 0)).collect();
+        assert_eq!(neighbours, [(1, 0), (0, 1)]);
+        let automaton = Automaton::new(automaton.grid, &ORTHOGONAL, Edges::Wrapping);
+        let neighbours: Vec<Coord> = automaton.neighbours((0, 0)).collect();
+        assert_eq!(neighbours, [(0, 2), (1, 0), (0, 1), (3, 0)]);
+    }
+}
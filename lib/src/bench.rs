@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::Instant;
+
+use crate::DEFAULT_VARIANT;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub revision: String,
+    pub machine: String,
+    pub year: String,
+    pub day: u8,
+    pub part: u8,
+    pub variant: String,
+    pub samples: Vec<u64>,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.revision,
+            self.machine,
+            self.year,
+            self.day,
+            self.part,
+            self.variant,
+            self.samples
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields: Vec<_> = line.split('\t').collect();
+        // histories written before variants existed have no variant column
+        if fields.len() == 6 {
+            fields.insert(5, DEFAULT_VARIANT);
+        }
+        let mut fields = fields.into_iter();
+        let record = Self {
+            revision: fields.next()?.to_string(),
+            machine: fields.next()?.to_string(),
+            year: fields.next()?.to_string(),
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            variant: fields.next()?.to_string(),
+            samples: fields
+                .next()?
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?,
+        };
+        fields.next().is_none().then_some(record)
+    }
+}
+
+pub fn measure<F: FnMut()>(mut f: F, samples: usize) -> Vec<u64> {
+    f();
+    (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX)
+        })
+        .collect()
+}
+
+pub fn load_history(path: &Path) -> io::Result<Vec<Record>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                Record::from_line(line).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("malformed history line: {line}"),
+                    )
+                })
+            })
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append_history(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for record in records {
+        writeln!(file, "{}", record.to_line())?;
+    }
+    Ok(())
+}
+
+/// Makes a name safe to store in a history field.
+pub fn sanitise(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| {
+            if c.is_whitespace() || c == ',' {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+pub fn git_revision() -> String {
+    let Some(revision) = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    else {
+        return "unknown".to_string();
+    };
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+    if dirty {
+        format!("{revision}-dirty")
+    } else {
+        revision
+    }
+}
+
+pub fn machine_name() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map_or_else(|| "unknown".to_string(), |name| sanitise(&name))
+}
+
+pub fn format_nanos(nanos: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let nanos = nanos as f64;
+    if nanos >= 1e9 {
+        format!("{:.3}s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.3}ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.3}µs", nanos / 1e3)
+    } else {
+        format!("{nanos}ns")
+    }
+}
+
+pub fn median(samples: &[u64]) -> u64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    match sorted.len() {
+        0 => 0,
+        n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        n => sorted[n / 2],
+    }
+}
+
+fn erfc(x: f64) -> f64 {
+    // Abramowitz & Stegun 7.1.26, accurate to ~1e-7
+    let t = 1.0 / 0.327_591_1_f64.mul_add(x.abs(), 1.0);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let result = poly * (-x * x).exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn mann_whitney_p(a: &[u64], b: &[u64]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 1.0;
+    }
+    let mut combined: Vec<(u64, bool)> = a
+        .iter()
+        .map(|x| (*x, true))
+        .chain(b.iter().map(|x| (*x, false)))
+        .collect();
+    combined.sort_unstable();
+    let mut rank_sum_a = 0.0;
+    let mut i = 0;
+    while i < combined.len() {
+        let mut j = i;
+        while j < combined.len() && combined[j].0 == combined[i].0 {
+            j += 1;
+        }
+        let rank = (i + j + 1) as f64 / 2.0;
+        rank_sum_a += rank * combined[i..j].iter().filter(|(_, in_a)| *in_a).count() as f64;
+        i = j;
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let u = n_a.mul_add(-(n_a + 1.0) / 2.0, rank_sum_a);
+    let mean = n_a * n_b / 2.0;
+    let deviation = (n_a * n_b * (n_a + n_b + 1.0) / 12.0).sqrt();
+    if deviation == 0.0 {
+        return 1.0;
+    }
+    let z = (u - mean).abs() / deviation;
+    erfc(z / std::f64::consts::SQRT_2).min(1.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Faster,
+    Slower,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub variant: String,
+    pub baseline: u64,
+    pub candidate: u64,
+    pub change: f64,
+    pub p_value: f64,
+    pub verdict: Verdict,
+}
+
+fn pool_samples(records: &[&Record]) -> BTreeMap<(u8, u8, String), Vec<u64>> {
+    let mut pooled: BTreeMap<(u8, u8, String), Vec<u64>> = BTreeMap::new();
+    for record in records {
+        pooled
+            .entry((record.day, record.part, record.variant.clone()))
+            .or_default()
+            .extend(&record.samples);
+    }
+    pooled
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn compare(
+    baseline: &[&Record],
+    candidate: &[&Record],
+    threshold: f64,
+    alpha: f64,
+) -> Vec<Comparison> {
+    let baseline = pool_samples(baseline);
+    pool_samples(candidate)
+        .into_iter()
+        .filter_map(|((day, part, variant), candidate_samples)| {
+            let baseline_samples = baseline.get(&(day, part, variant.clone()))?;
+            let (old, new) = (median(baseline_samples), median(&candidate_samples));
+            let change = if old == 0 {
+                0.0
+            } else {
+                (new as f64 - old as f64) / old as f64
+            };
+            let p_value = mann_whitney_p(baseline_samples, &candidate_samples);
+            let verdict = if p_value >= alpha || change.abs() < threshold {
+                Verdict::Unchanged
+            } else if change < 0.0 {
+                Verdict::Faster
+            } else {
+                Verdict::Slower
+            };
+            Some(Comparison {
+                day,
+                part,
+                variant,
+                baseline: old,
+                candidate: new,
+                change,
+                p_value,
+                verdict,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(revision: &str, samples: Vec<u64>) -> Record {
+        Record {
+            revision: revision.to_string(),
+            machine: "box".to_string(),
+            year: "ac2024".to_string(),
+            day: 6,
+            part: 2,
+            variant: DEFAULT_VARIANT.to_string(),
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let original = record("abc1234", vec![10, 20, 30]);
+        assert_eq!(
+            Record::from_line(&original.to_line()),
+            Some(original.clone())
+        );
+        assert_eq!(Record::from_line("abc\tbox\tac2024\t6"), None);
+        assert_eq!(
+            Record::from_line("abc1234\tbox\tac2024\t6\t2\t10,20,30"),
+            Some(original)
+        );
+        let mut named = record("abc1234", vec![10]);
+        named.machine = sanitise(" my\tbox\nb,c ");
+        assert_eq!(named.machine, "my_box_b_c");
+        assert_eq!(Record::from_line(&named.to_line()), Some(named));
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[5, 1, 3]), 3);
+        assert_eq!(median(&[4, 1, 3, 2]), 2);
+    }
+
+    #[test]
+    fn test_noise_is_unchanged() {
+        let old = record("old", vec![100, 103, 98, 101, 99, 102, 100, 97]);
+        let new = record("new", vec![101, 99, 102, 98, 100, 103, 97, 100]);
+        let result = compare(&[&old], &[&new], 0.05, 0.05);
+        assert_eq!(result[0].verdict, Verdict::Unchanged);
+    }
+
+    #[test]
+    fn test_regression_detected() {
+        let old = record("old", vec![100, 103, 98, 101, 99, 102, 100, 97]);
+        let new = record("new", vec![150, 149, 155, 160, 148, 152, 151, 153]);
+        let result = compare(&[&old], &[&new], 0.05, 0.05);
+        assert_eq!(result[0].verdict, Verdict::Slower);
+        let result = compare(&[&new], &[&old], 0.05, 0.05);
+        assert_eq!(result[0].verdict, Verdict::Faster);
+    }
+}
@@ -0,0 +1,161 @@
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+
+use crate::Answer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputCase {
+    pub path: PathBuf,
+    pub input: String,
+}
+
+impl InputCase {
+    pub fn expected_path(&self, part: u8) -> PathBuf {
+        self.path.with_extension(format!("part{part}"))
+    }
+
+    pub fn expected(&self, part: u8) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.expected_path(part)) {
+            Ok(answer) => Ok(Some(answer.trim_end_matches(['\n', '\r']).to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn is_answer_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extension
+                .strip_prefix("part")
+                .is_some_and(|part| part.parse::<u8>().is_ok())
+        })
+}
+
+pub fn load_inputs(dir: &Path) -> io::Result<Vec<InputCase>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file() && !is_answer_file(path));
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let input = fs::read_to_string(&path)?;
+            Ok(InputCase { path, input })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct(Answer),
+    Wrong { expected: String, actual: Answer },
+    Unverified(Answer),
+    Panicked(String),
+}
+
+impl Outcome {
+    pub const fn is_failure(&self) -> bool {
+        matches!(self, Self::Wrong { .. } | Self::Panicked(_))
+    }
+
+    pub const fn answer(&self) -> Option<&Answer> {
+        match self {
+            Self::Correct(actual) | Self::Wrong { actual, .. } | Self::Unverified(actual) => {
+                Some(actual)
+            }
+            Self::Panicked(_) => None,
+        }
+    }
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, keeping the panic message off stderr. The hook is only muted on
+/// this thread so parallel tests still report their own panics.
+pub fn catch<T, F: FnOnce() -> T>(f: F) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+    let quiet = QUIET.with(|quiet| quiet.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.with(|outer| outer.set(quiet));
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+pub fn check(run: fn(&str) -> Answer, input: &str, expected: Option<String>) -> Outcome {
+    match (catch(|| run(input)), expected) {
+        (Err(message), _) => Outcome::Panicked(message),
+        (Ok(actual), None) => Outcome::Unverified(actual),
+        (Ok(actual), Some(expected)) if actual.matches(&expected) => Outcome::Correct(actual),
+        (Ok(actual), Some(expected)) => Outcome::Wrong { expected, actual },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn double(input: &str) -> Answer {
+        (input.trim().parse::<u32>().expect("parsing error") * 2).into()
+    }
+
+    #[test]
+    fn test_load_inputs() {
+        let dir = std::env::temp_dir().join(format!("lib-check-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("b.txt"), "4\n").unwrap();
+        fs::write(dir.join("a.txt"), "2\n").unwrap();
+        fs::write(dir.join("a.part1"), "4\n").unwrap();
+        let cases = load_inputs(&dir).unwrap();
+        assert_eq!(
+            cases
+                .iter()
+                .map(|case| case.path.file_name().unwrap().to_str().unwrap())
+                .collect::<Vec<_>>(),
+            ["a.txt", "b.txt"]
+        );
+        assert_eq!(cases[0].expected(1).unwrap(), Some("4".to_string()));
+        assert_eq!(cases[0].expected(2).unwrap(), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(
+            check(double, "2", Some("4".to_string())),
+            Outcome::Correct(Answer::Integer(4))
+        );
+        assert_eq!(
+            check(double, "3", Some("4".to_string())),
+            Outcome::Wrong {
+                expected: "4".to_string(),
+                actual: Answer::Integer(6)
+            }
+        );
+        assert_eq!(
+            check(double, "x", None),
+            Outcome::Panicked("parsing error: ParseIntError { kind: InvalidDigit }".to_string())
+        );
+    }
+}
@@ -0,0 +1,192 @@
+type Check<'a, V> = Box<dyn Fn(&[Option<V>]) -> bool + 'a>;
+
+/// A check over the values of `scope`, in scope order. Unassigned variables
+/// are `None`; the check should only fail once the assigned values can no
+/// longer be part of a solution, so it can prune partial assignments.
+struct Constraint<'a, V> {
+    scope: Vec<usize>,
+    holds: Check<'a, V>,
+}
+
+/// A constraint-satisfaction problem over variables with finite domains,
+/// solved by backtracking with forward checking: a variable whose domain
+/// has shrunk to one value counts as assigned while pruning the others.
+pub struct Csp<'a, V> {
+    domains: Vec<Vec<V>>,
+    constraints: Vec<Constraint<'a, V>>,
+    watching: Vec<Vec<usize>>,
+}
+
+impl<V> Default for Csp<'_, V> {
+    fn default() -> Self {
+        Self {
+            domains: vec![],
+            constraints: vec![],
+            watching: vec![],
+        }
+    }
+}
+
+impl<'a, V: Copy + PartialEq> Csp<'a, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable, returning its index.
+    pub fn variable(&mut self, domain: impl IntoIterator<Item = V>) -> usize {
+        self.domains.push(domain.into_iter().collect());
+        self.watching.push(vec![]);
+        self.domains.len() - 1
+    }
+
+    pub fn constrain(&mut self, scope: Vec<usize>, holds: impl Fn(&[Option<V>]) -> bool + 'a) {
+        for variable in &scope {
+            self.watching[*variable].push(self.constraints.len());
+        }
+        self.constraints.push(Constraint {
+            scope,
+            holds: Box::new(holds),
+        });
+    }
+
+    pub fn all_different(&mut self, scope: Vec<usize>) {
+        self.constrain(scope, |values| {
+            let assigned: Vec<V> = values.iter().flatten().copied().collect();
+            assigned
+                .iter()
+                .enumerate()
+                .all(|(i, value)| !assigned[i + 1..].contains(value))
+        });
+    }
+
+    /// Checks a constraint with `variable` set to `value`, filling `values`
+    /// so propagation reuses one buffer for every check.
+    fn holds(
+        &self,
+        constraint: usize,
+        domains: &[Vec<V>],
+        (variable, value): (usize, V),
+        values: &mut Vec<Option<V>>,
+    ) -> bool {
+        let constraint = &self.constraints[constraint];
+        values.clear();
+        values.extend(
+            constraint
+                .scope
+                .iter()
+                .map(|other| match domains[*other].as_slice() {
+                    _ if *other == variable => Some(value),
+                    [only] => Some(*only),
+                    _ => None,
+                }),
+        );
+        (constraint.holds)(values)
+    }
+
+    /// Removes every value that breaks a constraint given the variables
+    /// already down to one value, until nothing changes. Returns `false`
+    /// when a domain runs empty.
+    fn propagate(&self, domains: &mut [Vec<V>]) -> bool {
+        let mut values = vec![];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for variable in 0..domains.len() {
+                let mut domain = std::mem::take(&mut domains[variable]);
+                let before = domain.len();
+                domain.retain(|value| {
+                    self.watching[variable].iter().all(|constraint| {
+                        self.holds(*constraint, domains, (variable, *value), &mut values)
+                    })
+                });
+                changed |= domain.len() != before;
+                domains[variable] = domain;
+                if domains[variable].is_empty() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn search(&self, mut domains: Vec<Vec<V>>, found: &mut dyn FnMut(Vec<V>) -> bool) -> bool {
+        if !self.propagate(&mut domains) {
+            return false;
+        }
+        let branch = (0..domains.len())
+            .filter(|variable| domains[*variable].len() > 1)
+            .min_by_key(|variable| domains[*variable].len());
+        let Some(variable) = branch else {
+            return found(domains.into_iter().map(|domain| domain[0]).collect());
+        };
+        for value in std::mem::take(&mut domains[variable]) {
+            let mut guess = domains.clone();
+            guess[variable] = vec![value];
+            if self.search(guess, found) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// The first solution found, one value per variable in index order.
+    pub fn solve(&self) -> Option<Vec<V>> {
+        let mut solution = None;
+        self.search(self.domains.clone(), &mut |found| {
+            solution = Some(found);
+            true
+        });
+        solution
+    }
+
+    pub fn solutions(&self) -> Vec<Vec<V>> {
+        let mut solutions = vec![];
+        self.search(self.domains.clone(), &mut |found| {
+            solutions.push(found);
+            false
+        });
+        solutions
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_all_different() {
+        let mut csp = Csp::new();
+        let vars: Vec<usize> = (0..3).map(|_| csp.variable(1..=3)).collect();
+        csp.all_different(vars.clone());
+        assert_eq!(csp.solutions().len(), 6);
+        csp.constrain(vec![vars[0], vars[1]], |values| match values {
+            [Some(a), Some(b)] => a > b,
+            _ => true,
+        });
+        csp.constrain(vec![vars[2]], |values| values[0] != Some(1));
+        assert_eq!(csp.solutions(), [[3, 1, 2], [2, 1, 3]]);
+        csp.constrain(vec![vars[2]], |values| values[0] != Some(3));
+        assert_eq!(csp.solve(), Some(vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn test_queens() {
+        let size = 8;
+        let mut csp = Csp::new();
+        let rows: Vec<usize> = (0..size).map(|_| csp.variable(0..size)).collect();
+        csp.all_different(rows.clone());
+        for a in 0..size {
+            for b in a + 1..size {
+                csp.constrain(vec![rows[a], rows[b]], move |values| match values {
+                    [Some(x), Some(y)] => x.abs_diff(*y) != b - a,
+                    _ => true,
+                });
+            }
+        }
+        assert_eq!(csp.solutions().len(), 92);
+        let mut csp = Csp::new();
+        let vars: Vec<usize> = (0..3).map(|_| csp.variable(0..2)).collect();
+        csp.all_different(vars);
+        assert_eq!(csp.solve(), None);
+    }
+}
@@ -0,0 +1,702 @@
+use std::collections::HashMap;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+pub type Coord = (i64, i64);
+
+pub const ORTHOGONAL: [Coord; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+pub const ADJACENT: [Coord; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// An inclusive rectangle of coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Bounds {
+    pub const fn point(at: Coord) -> Self {
+        Self { min: at, max: at }
+    }
+
+    pub fn include(&mut self, (x, y): Coord) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    #[must_use]
+    pub const fn grown(self, by: i64) -> Self {
+        Self {
+            min: (self.min.0 - by, self.min.1 - by),
+            max: (self.max.0 + by, self.max.1 + by),
+        }
+    }
+
+    pub const fn contains(&self, (x, y): Coord) -> bool {
+        self.min.0 <= x && x <= self.max.0 && self.min.1 <= y && y <= self.max.1
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub const fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub const fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    /// Every coordinate inside, row by row.
+    pub fn coords(self) -> impl Iterator<Item = Coord> {
+        (self.min.1..=self.max.1).flat_map(move |y| (self.min.0..=self.max.0).map(move |x| (x, y)))
+    }
+}
+
+/// The API shared by the dense and sparse grids.
+pub trait Cells<T> {
+    fn get(&self, at: Coord) -> Option<&T>;
+
+    fn get_mut(&mut self, at: Coord) -> Option<&mut T>;
+
+    /// The smallest rectangle holding every cell, `None` when empty.
+    fn bounds(&self) -> Option<Bounds>;
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a;
+
+    /// The cells at the given offsets from `at` that exist.
+    fn neighbours<'a>(
+        &'a self,
+        (x, y): Coord,
+        offsets: &'a [Coord],
+    ) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let at = (x + dx, y + dy);
+            self.get(at).map(|value| (at, value))
+        })
+    }
+
+    fn orthogonal<'a>(&'a self, at: Coord) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        self.neighbours(at, &ORTHOGONAL)
+    }
+
+    fn adjacent<'a>(&'a self, at: Coord) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        self.neighbours(at, &ADJACENT)
+    }
+
+    /// One line per row of `bounds`, missing cells passed as `None`.
+    fn render_bounds(&self, bounds: Bounds, cell: impl Fn(Option<&T>) -> char) -> Vec<String> {
+        (bounds.min.1..=bounds.max.1)
+            .map(|y| {
+                (bounds.min.0..=bounds.max.0)
+                    .map(|x| cell(self.get((x, y))))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn render(&self, cell: impl Fn(Option<&T>) -> char) -> Vec<String> {
+        self.bounds()
+            .map(|bounds| self.render_bounds(bounds, cell))
+            .unwrap_or_default()
+    }
+}
+
+/// A rectangular grid with its top-left cell at `(0, 0)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows must all have the same length"
+        );
+        Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn parse(input: &str, cell: impl Fn(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&cell).collect())
+                .collect(),
+        )
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    fn index(&self, (x, y): Coord) -> Option<usize> {
+        let x = usize::try_from(x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// Keeps the cells that pass `keep` at the same coordinates.
+    pub fn to_sparse(&self, keep: impl Fn(&T) -> bool) -> SparseGrid<T>
+    where
+        T: Clone,
+    {
+        self.iter()
+            .filter(|(_, value)| keep(value))
+            .map(|(at, value)| (at, value.clone()))
+            .collect()
+    }
+}
+
+impl<T> Cells<T> for Grid<T> {
+    fn get(&self, at: Coord) -> Option<&T> {
+        self.index(at).map(|i| &self.cells[i])
+    }
+
+    fn get_mut(&mut self, at: Coord) -> Option<&mut T> {
+        self.index(at).map(|i| &mut self.cells[i])
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (self.width > 0 && self.height > 0).then(|| Bounds {
+            min: (0, 0),
+            max: (self.width as i64 - 1, self.height as i64 - 1),
+        })
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (((i % width) as i64, (i / width) as i64), value))
+    }
+}
+
+/// A grid that only stores the cells it was given, at any coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, at: Coord) -> bool {
+        self.cells.contains_key(&at)
+    }
+
+    fn grow(&mut self, at: Coord) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(at),
+            None => self.bounds = Some(Bounds::point(at)),
+        }
+    }
+
+    pub fn insert(&mut self, at: Coord, value: T) -> Option<T> {
+        self.grow(at);
+        self.cells.insert(at, value)
+    }
+
+    pub fn get_or_insert_with(&mut self, at: Coord, value: impl FnOnce() -> T) -> &mut T {
+        self.grow(at);
+        self.cells.entry(at).or_insert_with(value)
+    }
+
+    /// Removes a cell, shrinking the bounds if it was on their edge.
+    pub fn remove(&mut self, at: Coord) -> Option<T> {
+        let removed = self.cells.remove(&at)?;
+        if self.bounds.is_some_and(|Bounds { min, max }| {
+            at.0 == min.0 || at.0 == max.0 || at.1 == min.1 || at.1 == max.1
+        }) {
+            self.bounds = None;
+            let coords: Vec<Coord> = self.cells.keys().copied().collect();
+            for at in coords {
+                self.grow(at);
+            }
+        }
+        Some(removed)
+    }
+
+    /// A dense copy of the bounding box, so the cell at `bounds.min` lands on
+    /// `(0, 0)`; missing cells become `fill`.
+    pub fn to_dense(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds else {
+            return Grid::from_rows(vec![]);
+        };
+        let mut grid = Grid::new(bounds.width(), bounds.height(), fill);
+        for ((x, y), value) in &self.cells {
+            grid[(x - bounds.min.0, y - bounds.min.1)] = value.clone();
+        }
+        grid
+    }
+}
+
+impl<T> Cells<T> for SparseGrid<T> {
+    fn get(&self, at: Coord) -> Option<&T> {
+        self.cells.get(&at)
+    }
+
+    fn get_mut(&mut self, at: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&at)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        self.cells.iter().map(|(at, value)| (*at, value))
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (at, value) in iter {
+            grid.insert(at, value);
+        }
+        grid
+    }
+}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        grid.to_sparse(|_| true)
+    }
+}
+
+impl<T> std::ops::Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, at: Coord) -> &T {
+        self.get(at).expect("coordinate outside the grid")
+    }
+}
+
+impl<T> std::ops::IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, at: Coord) -> &mut T {
+        self.get_mut(at).expect("coordinate outside the grid")
+    }
+}
+
+/// A dense grid of flags packed into bits, one run of words per row so rows
+/// can be combined and shifted a word at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(64);
+        Self {
+            width,
+            height,
+            stride,
+            words: vec![0; stride * height],
+        }
+    }
+
+    /// Sets the cells of `grid` that pass `keep`.
+    pub fn from_grid<T>(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for ((x, y), value) in grid.iter() {
+            if keep(value) {
+                bits.set(x as usize, y as usize);
+            }
+        }
+        bits
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    fn position(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(x < self.width && y < self.height, "bit outside the grid");
+        (y * self.stride + x / 64, 1 << (x % 64))
+    }
+
+    /// Whether the bit is set; anything outside the grid is unset.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && {
+            let (word, mask) = self.position(x, y);
+            self.words[word] & mask != 0
+        }
+    }
+
+    pub fn set(&mut self, x: usize, y: usize) {
+        let (word, mask) = self.position(x, y);
+        self.words[word] |= mask;
+    }
+
+    pub fn unset(&mut self, x: usize, y: usize) {
+        let (word, mask) = self.position(x, y);
+        self.words[word] &= !mask;
+    }
+
+    /// Marks a cell as visited, returning `false` if it already was.
+    pub fn visit(&mut self, x: usize, y: usize) -> bool {
+        let (word, mask) = self.position(x, y);
+        let fresh = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        fresh
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// The set cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, word)| {
+            let (y, base) = (i / self.stride, i % self.stride * 64);
+            let mut rest = *word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    (base + bit, y)
+                })
+            })
+        })
+    }
+
+    fn combine(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids differ in size"
+        );
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, *other);
+        }
+    }
+
+    /// Clears the bits past the last column, which shifts can leave behind.
+    fn trim(&mut self) {
+        if !self.width.is_multiple_of(64) {
+            let mask = (1 << (self.width % 64)) - 1;
+            for row in self.words.chunks_mut(self.stride) {
+                row[self.stride - 1] &= mask;
+            }
+        }
+    }
+
+    /// Moves every row `by` columns towards higher x, dropping bits that
+    /// fall off the edge.
+    #[must_use]
+    pub fn shifted_right(&self, by: usize) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+        let (words, bits) = (by / 64, by % 64);
+        for (from, to) in self
+            .words
+            .chunks(self.stride)
+            .zip(shifted.words.chunks_mut(self.stride))
+        {
+            for i in words..self.stride {
+                let low = from[i - words];
+                to[i] = low << bits;
+                if bits > 0 && i > words {
+                    to[i] |= from[i - words - 1] >> (64 - bits);
+                }
+            }
+        }
+        shifted.trim();
+        shifted
+    }
+
+    /// Moves every row `by` columns towards lower x.
+    #[must_use]
+    pub fn shifted_left(&self, by: usize) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+        let (words, bits) = (by / 64, by % 64);
+        for (from, to) in self
+            .words
+            .chunks(self.stride)
+            .zip(shifted.words.chunks_mut(self.stride))
+        {
+            for i in 0..self.stride.saturating_sub(words) {
+                to[i] = from[i + words] >> bits;
+                if bits > 0 && i + words + 1 < self.stride {
+                    to[i] |= from[i + words + 1] << (64 - bits);
+                }
+            }
+        }
+        shifted
+    }
+
+    /// Moves every row `by` rows towards higher y.
+    #[must_use]
+    pub fn shifted_down(&self, by: usize) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+        let skip = (by * self.stride).min(self.words.len());
+        shifted.words[skip..].copy_from_slice(&self.words[..self.words.len() - skip]);
+        shifted
+    }
+
+    /// Moves every row `by` rows towards lower y.
+    #[must_use]
+    pub fn shifted_up(&self, by: usize) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+        let skip = (by * self.stride).min(self.words.len());
+        let len = self.words.len() - skip;
+        shifted.words[..len].copy_from_slice(&self.words[skip..]);
+        shifted
+    }
+}
+
+impl BitAndAssign<&Self> for BitGrid {
+    fn bitand_assign(&mut self, other: &Self) {
+        self.combine(other, |a, b| a & b);
+    }
+}
+
+impl BitOrAssign<&Self> for BitGrid {
+    fn bitor_assign(&mut self, other: &Self) {
+        self.combine(other, |a, b| a | b);
+    }
+}
+
+impl BitXorAssign<&Self> for BitGrid {
+    fn bitxor_assign(&mut self, other: &Self) {
+        self.combine(other, |a, b| a ^ b);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: Self) -> BitGrid {
+        let mut out = self.clone();
+        out &= other;
+        out
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: Self) -> BitGrid {
+        let mut out = self.clone();
+        out |= other;
+        out
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: Self) -> BitGrid {
+        let mut out = self.clone();
+        out ^= other;
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sparse_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert((3, -2), 'a');
+        grid.insert((-5, 4), 'b');
+        grid.insert((0, 0), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: (-5, -2),
+                max: (3, 4)
+            })
+        );
+        assert_eq!(grid.remove((0, 0)), Some('c'));
+        assert_eq!(grid.bounds().map(|bounds| bounds.width()), Some(9));
+        grid.remove((-5, 4));
+        assert_eq!(grid.bounds(), Some(Bounds::point((3, -2))));
+        *grid.get_or_insert_with((1_000_000, 7), || 'd') = 'e';
+        assert_eq!(grid.get((1_000_000, 7)), Some(&'e'));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let dense = Grid::parse("abc\ndef\nghi", |c| c);
+        let around: Vec<char> = dense.adjacent((0, 0)).map(|(_, c)| *c).collect();
+        assert_eq!(around, ['b', 'd', 'e']);
+        let sparse = SparseGrid::from(&dense);
+        let mut next: Vec<char> = sparse.orthogonal((1, 1)).map(|(_, c)| *c).collect();
+        next.sort_unstable();
+        assert_eq!(next, ['b', 'd', 'f', 'h']);
+        assert_eq!(sparse.adjacent((1, 1)).count(), 8);
+        assert_eq!(dense[(2, 1)], 'f');
+    }
+
+    #[test]
+    fn test_conversions_and_render() {
+        let sparse: SparseGrid<u8> = [((-1, -1), 1), ((1, 0), 2)].into_iter().collect();
+        assert_eq!(
+            sparse.render(|cell| cell.map_or('.', |_| '#')),
+            ["#..", "..#"]
+        );
+        let dense = sparse.to_dense(0);
+        assert_eq!((dense.width(), dense.height()), (3, 2));
+        assert_eq!(dense.rows().collect::<Vec<_>>(), [[1, 0, 0], [0, 0, 2]]);
+        assert_eq!(dense.to_sparse(|value| *value != 0).len(), 2);
+        assert_eq!(
+            dense.render(|cell| cell.map_or('?', |value| char::from(b'0' + value))),
+            ["100", "002"]
+        );
+    }
+
+    fn bits(width: usize, height: usize, set: &[(usize, usize)]) -> BitGrid {
+        let mut grid = BitGrid::new(width, height);
+        for (x, y) in set {
+            grid.set(*x, *y);
+        }
+        grid
+    }
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::new(130, 3);
+        assert!(grid.visit(129, 2));
+        assert!(!grid.visit(129, 2));
+        assert!(grid.get(129, 2) && !grid.get(130, 2) && !grid.get(0, 5));
+        grid.set(0, 0);
+        grid.set(64, 1);
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [(0, 0), (64, 1), (129, 2)]);
+        assert_eq!(grid.count(), 3);
+        grid.unset(0, 0);
+        assert_eq!(grid.count(), 2);
+        let other = bits(130, 3, &[(64, 1), (5, 0)]);
+        assert_eq!((&grid & &other).iter().collect::<Vec<_>>(), [(64, 1)]);
+        assert_eq!((&grid | &other).count(), 3);
+        assert_eq!(
+            (&grid ^ &other).iter().collect::<Vec<_>>(),
+            [(5, 0), (129, 2)]
+        );
+        let dense = Grid::parse("#.#\n.#.", |c| c == '#');
+        assert_eq!(BitGrid::from_grid(&dense, |lit| *lit).count(), 3);
+        grid.clear();
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_bit_shifts() {
+        let grid = bits(130, 3, &[(0, 0), (63, 0), (64, 1), (129, 2)]);
+        assert_eq!(
+            grid.shifted_right(1).iter().collect::<Vec<_>>(),
+            [(1, 0), (64, 0), (65, 1)]
+        );
+        assert_eq!(
+            grid.shifted_right(66).iter().collect::<Vec<_>>(),
+            [(66, 0), (129, 0)]
+        );
+        assert_eq!(
+            grid.shifted_left(1).iter().collect::<Vec<_>>(),
+            [(62, 0), (63, 1), (128, 2)]
+        );
+        assert_eq!(grid.shifted_left(65).iter().collect::<Vec<_>>(), [(64, 2)]);
+        assert_eq!(
+            grid.shifted_down(1).iter().collect::<Vec<_>>(),
+            [(0, 1), (63, 1), (64, 2)]
+        );
+        assert_eq!(grid.shifted_up(2).iter().collect::<Vec<_>>(), [(129, 0)]);
+        assert!(grid.shifted_down(3).is_empty());
+    }
+}
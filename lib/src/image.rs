@@ -0,0 +1,282 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+
+pub struct Picture {
+    pub year: &'static str,
+    pub day: u8,
+    pub name: &'static str,
+    pub render: fn(&str) -> Image,
+    pub input: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, colour: impl Fn(usize, usize) -> Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| colour(x, y))
+                .collect(),
+        }
+    }
+
+    /// Renders a dense row-major grid; short rows are padded with black.
+    pub fn from_grid<T, R: AsRef<[T]>>(grid: &[R], colour: impl Fn(&T) -> Rgb) -> Self {
+        let width = grid.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+        Self::from_fn(width, grid.len(), |x, y| {
+            grid[y].as_ref().get(x).map_or(BLACK, &colour)
+        })
+    }
+
+    /// Renders a sparse set of points, cropped to their bounding box.
+    pub fn from_points<T>(
+        points: impl IntoIterator<Item = ((i64, i64), T)>,
+        background: Rgb,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let Some(x_min) = points.iter().map(|((x, _), _)| *x).min() else {
+            return Self::new(0, 0, background);
+        };
+        let x_max = points.iter().map(|((x, _), _)| *x).max().unwrap_or(x_min);
+        let y_min = points.iter().map(|((_, y), _)| *y).min().unwrap_or(0);
+        let y_max = points.iter().map(|((_, y), _)| *y).max().unwrap_or(y_min);
+        let width = usize::try_from(x_max - x_min + 1).expect("image too wide");
+        let height = usize::try_from(y_max - y_min + 1).expect("image too tall");
+        let mut image = Self::new(width, height, background);
+        for ((x, y), value) in &points {
+            let (x, y) = ((x - x_min) as usize, (y - y_min) as usize);
+            image.set(x, y, colour(value));
+        }
+        image
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    #[must_use]
+    pub fn scaled(&self, factor: usize) -> Self {
+        let factor = factor.max(1);
+        Self::from_fn(self.width * factor, self.height * factor, |x, y| {
+            self.pixels[y / factor * self.width + x / factor]
+        })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.pixels.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for pixel in &self.pixels {
+            out.write_all(pixel)?;
+        }
+        out.flush()
+    }
+
+    pub fn write_pgm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P5\n{} {}\n255\n", self.width, self.height)?;
+        for pixel in &self.pixels {
+            out.write_all(&[luminance(*pixel)])?;
+        }
+        out.flush()
+    }
+
+    pub fn write_png<W: Write>(&self, mut out: W) -> io::Result<()> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, "image too large for png");
+        let width = u32::try_from(self.width).map_err(|_| invalid())?;
+        let height = u32::try_from(self.height).map_err(|_| invalid())?;
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+        let mut header = vec![];
+        header.extend(width.to_be_bytes());
+        header.extend(height.to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+        write_chunk(&mut out, b"IHDR", &header)?;
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.rows() {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&raw))?;
+        write_chunk(&mut out, b"IEND", &[])?;
+        out.flush()
+    }
+
+    /// Writes the image in the format given by the extension of `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let out = BufWriter::new(File::create(path)?);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => self.write_png(out),
+            Some("pgm") => self.write_pgm(out),
+            Some("ppm") => self.write_ppm(out),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "expected a .png, .pgm or .ppm path",
+            )),
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn luminance([r, g, b]: Rgb) -> u8 {
+    ((299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)) / 1000) as u8
+}
+
+/// Linear interpolation between two colours, `t` running from 0 to 1.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn blend(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    [0, 1, 2]
+        .map(|i| (f64::from(from[i]) + (f64::from(to[i]) - f64::from(from[i])) * t).round() as u8)
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(u8::from(blocks.peek().is_none()));
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn write_chunk<W: Write>(out: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "png chunk too large"))?;
+    out.write_all(&len.to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let mut checked = kind.to_vec();
+    checked.extend(data);
+    out.write_all(&crc32(&checked).to_be_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WHITE: Rgb = [255, 255, 255];
+
+    fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+        let mut out = vec![];
+        let mut position = 2;
+        loop {
+            let last = zlib[position] & 1 == 1;
+            let len = usize::from(u16::from_le_bytes([zlib[position + 1], zlib[position + 2]]));
+            out.extend(&zlib[position + 5..position + 5 + len]);
+            position += 5 + len;
+            if last {
+                return out;
+            }
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_dense_and_sparse() {
+        let grid = vec![vec![true, false], vec![false]];
+        let image = Image::from_grid(&grid, |lit| if *lit { WHITE } else { BLACK });
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.get(0, 0), Some(WHITE));
+        assert_eq!(image.get(1, 1), Some(BLACK));
+        let sparse = Image::from_points([((-1, 5), ()), ((1, 6), ())], BLACK, |()| WHITE);
+        assert_eq!((sparse.width(), sparse.height()), (3, 2));
+        assert_eq!(sparse.get(2, 1), Some(WHITE));
+        assert_eq!(sparse.get(1, 0), Some(BLACK));
+        let scaled = sparse.scaled(3);
+        assert_eq!((scaled.width(), scaled.height()), (9, 6));
+        assert_eq!(scaled.get(8, 5), Some(WHITE));
+    }
+
+    #[test]
+    fn test_formats() {
+        let image = Image::from_fn(300, 250, |x, y| [x as u8, y as u8, 7]);
+        let mut pgm = vec![];
+        image.write_pgm(&mut pgm).unwrap();
+        assert!(pgm.starts_with(b"P5\n300 250\n255\n"));
+        assert_eq!(pgm.len(), 15 + 300 * 250);
+        let mut png = vec![];
+        image.write_png(&mut png).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let raw = inflate_stored(&png[41..41 + idat_len]);
+        assert_eq!(raw.len(), 250 * (300 * 3 + 1));
+        assert_eq!(&raw[(300 * 3 + 1) * 2..][..7], &[0, 0, 2, 7, 1, 2, 7]);
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+}
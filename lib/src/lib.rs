@@ -1,27 +1,96 @@
 pub use paste::paste;
 
+pub mod alloc;
+mod answer;
+pub mod automaton;
+pub mod bench;
+pub mod check;
+pub mod csp;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod num;
+pub mod ocr;
+pub mod prop;
+mod runner;
+pub mod viz;
+
+pub use answer::Answer;
+pub use runner::{run, Solution, DEFAULT_VARIANT};
+
 #[macro_export]
 macro_rules! solution {
-    ( $day:literal, 1 ) => {{
+    ( $day:literal, $part:literal ) => {{
+        use lib::paste;
+        paste! {
+            lib::Solution {
+                year: env!("CARGO_PKG_NAME"),
+                day: $day,
+                part: $part,
+                variant: lib::DEFAULT_VARIANT,
+                run: [<day $day>]::[<part $part>],
+                input: include_str!(concat!("../input/p", stringify!($day))),
+            }
+        }
+    }};
+    ( $day:literal, $part:literal, $variant:ident ) => {{
         use lib::paste;
         paste! {
-            use [<p $day>] as p;
-            println!(
-                "Day {} part 1: {}",
-                $day,
-                p::part1(include_str!(concat!("../input/p", stringify!($day))))
-            )
+            lib::Solution {
+                year: env!("CARGO_PKG_NAME"),
+                day: $day,
+                part: $part,
+                variant: stringify!($variant),
+                run: [<day $day>]::[<part $part _ $variant>],
+                input: include_str!(concat!("../input/p", stringify!($day))),
+            }
         }
     }};
-    ( $day:literal, 2 ) => {{
+}
+
+#[macro_export]
+macro_rules! animation {
+    ( $day:literal, $name:ident ) => {{
+        use lib::paste;
+        paste! {
+            lib::viz::Animation {
+                year: env!("CARGO_PKG_NAME"),
+                day: $day,
+                name: stringify!($name),
+                build: |input| Box::new([<day $day>]::$name(input)),
+                input: include_str!(concat!("../input/p", stringify!($day))),
+            }
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! picture {
+    ( $day:literal, $name:ident ) => {{
+        use lib::paste;
+        paste! {
+            lib::image::Picture {
+                year: env!("CARGO_PKG_NAME"),
+                day: $day,
+                name: stringify!($name),
+                render: [<day $day>]::$name,
+                input: include_str!(concat!("../input/p", stringify!($day))),
+            }
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! generator {
+    ( $day:literal, $name:ident ) => {{
         use lib::paste;
         paste! {
-            use [<p $day>] as p;
-            println!(
-                "Day {} part 2: {}",
-                $day,
-                p::part2(include_str!(concat!("../input/p", stringify!($day))))
-            )
+            lib::generate::Generator {
+                year: env!("CARGO_PKG_NAME"),
+                day: $day,
+                name: stringify!($name),
+                generate: [<day $day>]::$name,
+            }
         }
     }};
 }
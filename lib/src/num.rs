@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl Error for Overflow {}
+
+/// Non-negative integers with checked arithmetic. Fixed-width types report
+/// overflow (and subtraction below zero) as an error; [`BigUint`] only fails
+/// on subtraction below zero.
+pub trait Natural: Clone + Ord + Debug + Display {
+    fn from_u64(value: u64) -> Self;
+    fn add(&self, other: &Self) -> Result<Self, Overflow>;
+    fn sub(&self, other: &Self) -> Result<Self, Overflow>;
+    fn mul(&self, other: &Self) -> Result<Self, Overflow>;
+
+    fn zero() -> Self {
+        Self::from_u64(0)
+    }
+
+    fn abs_diff(&self, other: &Self) -> Self {
+        let (high, low) = if self >= other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        high.sub(low)
+            .expect("larger minus smaller cannot underflow")
+    }
+
+    fn sum<'a>(values: impl IntoIterator<Item = &'a Self>) -> Result<Self, Overflow>
+    where
+        Self: 'a,
+    {
+        values
+            .into_iter()
+            .try_fold(Self::zero(), |total, value| total.add(value))
+    }
+}
+
+macro_rules! natural {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Natural for $t {
+                fn from_u64(value: u64) -> Self {
+                    Self::try_from(value).expect("value does not fit")
+                }
+
+                fn add(&self, other: &Self) -> Result<Self, Overflow> {
+                    self.checked_add(*other).ok_or(Overflow)
+                }
+
+                fn sub(&self, other: &Self) -> Result<Self, Overflow> {
+                    self.checked_sub(*other).ok_or(Overflow)
+                }
+
+                fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+                    self.checked_mul(*other).ok_or(Overflow)
+                }
+            }
+        )*
+    };
+}
+
+natural!(u32, u64, u128, usize);
+
+/// Residues modulo `M`, for when only the remainder of a huge count is
+/// wanted. Arithmetic wraps around and never fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Modular<const M: u64>(u64);
+
+impl<const M: u64> Modular<M> {
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl<const M: u64> Display for Modular<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl<const M: u64> Natural for Modular<M> {
+    fn from_u64(value: u64) -> Self {
+        Self(value % M)
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(Self(
+            ((u128::from(self.0) + u128::from(other.0)) % u128::from(M)) as u64,
+        ))
+    }
+
+    fn sub(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(Self(
+            ((u128::from(self.0) + u128::from(M) - u128::from(other.0)) % u128::from(M)) as u64,
+        ))
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(Self(
+            ((u128::from(self.0) * u128::from(other.0)) % u128::from(M)) as u64,
+        ))
+    }
+}
+
+impl<const M: u64> From<Modular<M>> for Answer {
+    fn from(value: Modular<M>) -> Self {
+        value.value().into()
+    }
+}
+
+/// An arbitrary-precision unsigned integer, stored as little-endian base
+/// 2^32 limbs without trailing zero limbs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    fn normalised(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Divides in place by a small divisor, returning the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | u64::from(*limb);
+            *limb = u32::try_from(current / u64::from(divisor)).expect("quotient fits a limb");
+            remainder = current % u64::from(divisor);
+        }
+        *self = Self::normalised(std::mem::take(&mut self.limbs));
+        u32::try_from(remainder).expect("remainder fits a limb")
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self::from(u128::from(value))
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl From<u128> for BigUint {
+    fn from(mut value: u128) -> Self {
+        let mut limbs = vec![];
+        while value > 0 {
+            limbs.push(value as u32);
+            value >>= 32;
+        }
+        Self { limbs }
+    }
+}
+
+impl TryFrom<&BigUint> for u128 {
+    type Error = Overflow;
+
+    fn try_from(value: &BigUint) -> Result<Self, Overflow> {
+        if value.limbs.len() > 4 {
+            return Err(Overflow);
+        }
+        Ok(value
+            .limbs
+            .iter()
+            .rev()
+            .fold(0, |total, limb| (total << 32) | Self::from(*limb)))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl Natural for BigUint {
+    fn from_u64(value: u64) -> Self {
+        Self::from(value)
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, Overflow> {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0u64;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = u64::from(*self.limbs.get(i).unwrap_or(&0))
+                + u64::from(*other.limbs.get(i).unwrap_or(&0))
+                + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        Ok(Self::normalised(limbs))
+    }
+
+    fn sub(&self, other: &Self) -> Result<Self, Overflow> {
+        if self < other {
+            return Err(Overflow);
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let mut difference =
+                i64::from(*limb) - i64::from(*other.limbs.get(i).unwrap_or(&0)) - borrow;
+            borrow = i64::from(difference < 0);
+            difference += borrow << 32;
+            limbs.push(difference as u32);
+        }
+        Ok(Self::normalised(limbs))
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let product = u64::from(*a) * u64::from(*b) + u64::from(limbs[i + j]) + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        Ok(Self::normalised(limbs))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+        if self.is_zero() {
+            return f.pad("0");
+        }
+        let mut rest = self.clone();
+        let mut chunks = vec![];
+        while !rest.is_zero() {
+            chunks.push(rest.div_rem_small(CHUNK));
+        }
+        let mut digits = chunks
+            .pop()
+            .map(|chunk| chunk.to_string())
+            .unwrap_or_default();
+        for chunk in chunks.iter().rev() {
+            digits += &format!("{chunk:09}");
+        }
+        f.pad(&digits)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        u128::try_from(&value).map_or_else(|_| Self::Text(value.to_string()), Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn big(value: u128) -> BigUint {
+        BigUint::from(value)
+    }
+
+    #[test]
+    fn test_checked_fixed_width() {
+        assert_eq!(Natural::add(&u64::MAX, &1), Err(Overflow));
+        assert_eq!(Natural::sub(&3u32, &4), Err(Overflow));
+        assert_eq!(Natural::mul(&6u128, &7), Ok(42));
+        assert_eq!(Natural::abs_diff(&3usize, &10), 7);
+        assert_eq!(u64::sum(&[1, 2, u64::MAX]), Err(Overflow));
+    }
+
+    #[test]
+    fn test_modular() {
+        type Mod7 = Modular<7>;
+        let (three, five) = (Mod7::from_u64(10), Mod7::from_u64(5));
+        assert_eq!(three.add(&five).unwrap().value(), 1);
+        assert_eq!(three.sub(&five).unwrap().value(), 5);
+        assert_eq!(three.mul(&five).unwrap().value(), 1);
+        let big = Modular::<{ u64::MAX - 58 }>::from_u64(u64::MAX - 59);
+        assert_eq!(big.mul(&big).unwrap().value(), 1);
+        assert_eq!(big.add(&big).unwrap().value(), u64::MAX - 60);
+    }
+
+    #[test]
+    fn test_big_arithmetic() {
+        let max = big(u128::MAX);
+        let one = big(1);
+        let sum = max.add(&one).unwrap();
+        assert_eq!(sum.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(sum.sub(&one), Ok(max.clone()));
+        assert_eq!(one.sub(&sum), Err(Overflow));
+        assert_eq!(
+            max.mul(&max).unwrap().to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(
+            big(1_000_000_000)
+                .mul(&big(1_000_000_007))
+                .unwrap()
+                .to_string(),
+            "1000000007000000000"
+        );
+        assert_eq!(big(0).to_string(), "0");
+        assert!(big(5) < sum && big(1 << 40) > big(1 << 39));
+        assert_eq!(Answer::from(big(42)), Answer::Integer(42));
+        assert_eq!(Answer::from(sum.clone()), Answer::Text(sum.to_string()));
+    }
+}
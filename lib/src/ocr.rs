@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::sync::OnceLock;
+
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+type Glyph = Vec<Vec<bool>>;
+
+fn parse_art(art: &str) -> Glyph {
+    art.lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect()
+}
+
+fn render(glyph: &[Vec<bool>]) -> String {
+    glyph
+        .iter()
+        .map(|row| row.iter().map(|lit| if *lit { '#' } else { '.' }).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn font(height: usize) -> Option<&'static HashMap<Glyph, char>> {
+    static SMALL: OnceLock<HashMap<Glyph, char>> = OnceLock::new();
+    static LARGE: OnceLock<HashMap<Glyph, char>> = OnceLock::new();
+    let build = |font: &[(char, &str)]| {
+        font.iter()
+            .map(|(letter, art)| (trim_columns(&parse_art(art)), *letter))
+            .collect()
+    };
+    match height {
+        6 => Some(SMALL.get_or_init(|| build(&SMALL_FONT))),
+        10 => Some(LARGE.get_or_init(|| build(&LARGE_FONT))),
+        _ => None,
+    }
+}
+
+fn column_is_empty(bitmap: &[Vec<bool>], column: usize) -> bool {
+    bitmap
+        .iter()
+        .all(|row| !row.get(column).copied().unwrap_or(false))
+}
+
+fn trim_columns(glyph: &[Vec<bool>]) -> Glyph {
+    let width = glyph.iter().map(Vec::len).max().unwrap_or(0);
+    let start = (0..width)
+        .find(|column| !column_is_empty(glyph, *column))
+        .unwrap_or(width);
+    let end = (start..width)
+        .rfind(|column| !column_is_empty(glyph, *column))
+        .map_or(start, |column| column + 1);
+    glyph
+        .iter()
+        .map(|row| {
+            (start..end)
+                .map(|column| row.get(column).copied().unwrap_or(false))
+                .collect()
+        })
+        .collect()
+}
+
+fn trim_rows(bitmap: &[Vec<bool>]) -> &[Vec<bool>] {
+    let lit = |row: &Vec<bool>| row.iter().any(|lit| *lit);
+    let start = bitmap.iter().position(lit).unwrap_or(bitmap.len());
+    let end = bitmap.iter().rposition(lit).map_or(start, |row| row + 1);
+    &bitmap[start..end]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    pub index: usize,
+    pub column: usize,
+    pub art: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    UnsupportedHeight(usize),
+    UnknownGlyphs {
+        decoded: String,
+        unknown: Vec<UnknownGlyph>,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedHeight(height) => {
+                write!(f, "no font with letters {height} pixels high")
+            }
+            Self::UnknownGlyphs { decoded, unknown } => {
+                write!(f, "unrecognised glyphs in {decoded}")?;
+                for glyph in unknown {
+                    write!(
+                        f,
+                        "\nglyph {} at column {}:\n{}",
+                        glyph.index, glyph.column, glyph.art
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn decode(bitmap: &[Vec<bool>]) -> Result<String, OcrError> {
+    let bitmap = if matches!(bitmap.len(), 6 | 10) {
+        bitmap
+    } else {
+        trim_rows(bitmap)
+    };
+    let font = font(bitmap.len()).ok_or(OcrError::UnsupportedHeight(bitmap.len()))?;
+    let width = bitmap.iter().map(Vec::len).max().unwrap_or(0);
+    let mut decoded = String::new();
+    let mut unknown = vec![];
+    let mut column = 0;
+    while column < width {
+        if column_is_empty(bitmap, column) {
+            column += 1;
+            continue;
+        }
+        let start = column;
+        while column < width && !column_is_empty(bitmap, column) {
+            column += 1;
+        }
+        let glyph: Glyph = bitmap
+            .iter()
+            .map(|row| {
+                (start..column)
+                    .map(|x| row.get(x).copied().unwrap_or(false))
+                    .collect()
+            })
+            .collect();
+        if let Some(letter) = font.get(&glyph) {
+            decoded.push(*letter);
+        } else {
+            unknown.push(UnknownGlyph {
+                index: decoded.chars().count(),
+                column: start,
+                art: render(&glyph),
+            });
+            decoded.push('?');
+        }
+    }
+    if unknown.is_empty() {
+        Ok(decoded)
+    } else {
+        Err(OcrError::UnknownGlyphs { decoded, unknown })
+    }
+}
+
+pub fn decode_art(art: &str) -> Result<String, OcrError> {
+    decode(&parse_art(art))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_small_font() {
+        let art = "
+###..#..#.####.###..
+#..#.#..#.#....#..#.
+#..#.####.###..#..#.
+###..#..#.#....###..
+#....#..#.#....#.#..
+#....#..#.####.#..#.
+";
+        assert_eq!(decode_art(art), Ok("PHER".to_string()));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let art = LARGE_FONT
+            .iter()
+            .map(|(_, art)| parse_art(art))
+            .reduce(|mut left, right| {
+                for (row, extra) in left.iter_mut().zip(right) {
+                    row.extend([false, false]);
+                    row.extend(extra);
+                }
+                left
+            })
+            .unwrap();
+        assert_eq!(decode(&art), Ok("ABCEFGHJKLNPRXZ".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let art = "
+####.#..#
+#....#..#
+###..####
+#....#..#
+#....#..#
+####.#.##
+";
+        assert_eq!(
+            decode_art(art),
+            Err(OcrError::UnknownGlyphs {
+                decoded: "E?".to_string(),
+                unknown: vec![UnknownGlyph {
+                    index: 1,
+                    column: 5,
+                    art: "#..#\n#..#\n####\n#..#\n#..#\n#.##".to_string()
+                }]
+            })
+        );
+        assert_eq!(decode_art("#\n#"), Err(OcrError::UnsupportedHeight(2)));
+    }
+}
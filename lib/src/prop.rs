@@ -0,0 +1,292 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use crate::check;
+use crate::generate::Rng;
+
+const DEFAULT_CASES: usize = 100;
+const DEFAULT_SEED: u64 = 0x5eed;
+const MAX_SHRINKS: usize = 10_000;
+
+/// Generates values for a property and proposes simpler versions of a
+/// failing value; the first candidate that still fails is kept.
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value;
+
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        vec![]
+    }
+}
+
+pub struct Ints {
+    range: RangeInclusive<u64>,
+}
+
+pub const fn ints(range: RangeInclusive<u64>) -> Ints {
+    Ints { range }
+}
+
+impl Strategy for Ints {
+    type Value = u64;
+
+    fn generate(&self, rng: &mut Rng, _size: usize) -> u64 {
+        let (low, high) = (*self.range.start(), *self.range.end());
+        let span = high - low;
+        if span == u64::MAX {
+            rng.next_u64()
+        } else {
+            low + ((u128::from(rng.next_u64()) * u128::from(span + 1)) >> 64) as u64
+        }
+    }
+
+    fn shrink(&self, value: &u64) -> Vec<u64> {
+        let low = *self.range.start();
+        let mut candidates = vec![];
+        let mut distance = value - low;
+        while distance > 0 {
+            candidates.push(value - distance);
+            distance /= 2;
+        }
+        candidates
+    }
+}
+
+pub struct Chars {
+    alphabet: Vec<char>,
+}
+
+/// Picks characters from `alphabet`, shrinking towards its first character.
+pub fn chars(alphabet: &str) -> Chars {
+    Chars {
+        alphabet: alphabet.chars().collect(),
+    }
+}
+
+impl Strategy for Chars {
+    type Value = char;
+
+    fn generate(&self, rng: &mut Rng, _size: usize) -> char {
+        *rng.choose(&self.alphabet)
+    }
+
+    fn shrink(&self, value: &char) -> Vec<char> {
+        self.alphabet
+            .iter()
+            .take_while(|c| *c != value)
+            .copied()
+            .collect()
+    }
+}
+
+pub struct Vecs<S> {
+    element: S,
+    len: RangeInclusive<usize>,
+}
+
+/// Vectors whose length grows with the case size, up to the end of `len`.
+pub const fn vecs<S: Strategy>(element: S, len: RangeInclusive<usize>) -> Vecs<S> {
+    Vecs { element, len }
+}
+
+impl<S: Strategy> Strategy for Vecs<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value {
+        let (min, max) = (*self.len.start(), *self.len.end());
+        let len = rng.range(min..max.min(min + size).max(min) + 1);
+        (0..len).map(|_| self.element.generate(rng, size)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let min = *self.len.start();
+        let mut candidates = vec![];
+        let mut chunk = value.len() / 2;
+        while chunk > 0 {
+            if value.len() - chunk >= min {
+                for start in (0..=value.len() - chunk).step_by(chunk) {
+                    let mut smaller = value[..start].to_vec();
+                    smaller.extend_from_slice(&value[start + chunk..]);
+                    candidates.push(smaller);
+                }
+            }
+            chunk /= 2;
+        }
+        if value.len() > min {
+            for i in 0..value.len() {
+                let mut smaller = value.clone();
+                smaller.remove(i);
+                candidates.push(smaller);
+            }
+        }
+        for (i, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut smaller = value.clone();
+                smaller[i] = simpler;
+                candidates.push(smaller);
+            }
+        }
+        candidates
+    }
+}
+
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value {
+        (self.0.generate(rng, size), self.1.generate(rng, size))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let mut candidates: Vec<_> = self
+            .0
+            .shrink(a)
+            .into_iter()
+            .map(|a| (a, b.clone()))
+            .collect();
+        candidates.extend(self.1.shrink(b).into_iter().map(|b| (a.clone(), b)));
+        candidates
+    }
+}
+
+impl<A: Strategy, B: Strategy, C: Strategy> Strategy for (A, B, C) {
+    type Value = (A::Value, B::Value, C::Value);
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value {
+        (
+            self.0.generate(rng, size),
+            self.1.generate(rng, size),
+            self.2.generate(rng, size),
+        )
+    }
+
+    fn shrink(&self, (a, b, c): &Self::Value) -> Vec<Self::Value> {
+        let mut candidates: Vec<_> = self
+            .0
+            .shrink(a)
+            .into_iter()
+            .map(|a| (a, b.clone(), c.clone()))
+            .collect();
+        candidates.extend(
+            self.1
+                .shrink(b)
+                .into_iter()
+                .map(|b| (a.clone(), b, c.clone())),
+        );
+        candidates.extend(
+            self.2
+                .shrink(c)
+                .into_iter()
+                .map(|c| (a.clone(), b.clone(), c)),
+        );
+        candidates
+    }
+}
+
+pub struct FromFn<G, S> {
+    generate: G,
+    shrink: S,
+}
+
+/// A strategy for a puzzle model, built from a generator and a shrinker.
+pub const fn from_fn<T, G, S>(generate: G, shrink: S) -> FromFn<G, S>
+where
+    G: Fn(&mut Rng, usize) -> T,
+    S: Fn(&T) -> Vec<T>,
+{
+    FromFn { generate, shrink }
+}
+
+impl<T, G, S> Strategy for FromFn<G, S>
+where
+    T: Clone + Debug,
+    G: Fn(&mut Rng, usize) -> T,
+    S: Fn(&T) -> Vec<T>,
+{
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> T {
+        (self.generate)(rng, size)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        (self.shrink)(value)
+    }
+}
+
+fn fails<T>(property: &impl Fn(&T), value: &T) -> Option<String> {
+    check::catch(|| property(value)).err()
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Checks `property` on generated values, panicking with a shrunk
+/// counterexample on failure. `PROP_CASES` and `PROP_SEED` override the
+/// number of cases and the seed.
+pub fn for_all<S: Strategy>(strategy: &S, property: impl Fn(&S::Value)) {
+    let cases = env_or("PROP_CASES", DEFAULT_CASES);
+    let seed = env_or("PROP_SEED", DEFAULT_SEED);
+    let mut rng = Rng::new(seed);
+    for case in 0..cases {
+        let value = strategy.generate(&mut rng, case * 100 / cases.max(1) + 1);
+        let Some(mut error) = fails(&property, &value) else {
+            continue;
+        };
+        let mut minimal = value;
+        let mut shrinks = 0;
+        'shrinking: while shrinks < MAX_SHRINKS {
+            for candidate in strategy.shrink(&minimal) {
+                if let Some(candidate_error) = fails(&property, &candidate) {
+                    minimal = candidate;
+                    error = candidate_error;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        panic!(
+            "property failed on case {case} with seed {seed}, shrunk {shrinks} times to\n\
+             {minimal:#?}\n{error}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn failure<S: Strategy>(strategy: &S, property: impl Fn(&S::Value)) -> String {
+        check::catch(|| for_all(strategy, property)).expect_err("property should fail")
+    }
+
+    #[test]
+    fn test_passing_property() {
+        for_all(&vecs(ints(0..=100), 0..=20), |values| {
+            let mut sorted = values.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted.len(), values.len());
+        });
+    }
+
+    #[test]
+    fn test_shrinks_to_minimal() {
+        let error = failure(&ints(0..=1000), |x| assert!(*x < 300, "too big"));
+        assert!(error.contains("shrunk"), "{error}");
+        assert!(error.contains("\n300\ntoo big"), "{error}");
+        let error = failure(&vecs(ints(0..=100), 0..=30), |values| {
+            assert!(values.iter().all(|value| *value < 50));
+        });
+        assert!(error.contains("[\n    50,\n]"), "{error}");
+        let error = failure(&(chars("abc"), ints(0..=9)), |(c, x)| {
+            assert!(*c != 'c' || *x < 5);
+        });
+        assert!(error.contains("(\n    'c',\n    5,\n)"), "{error}");
+    }
+}
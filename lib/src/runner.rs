@@ -0,0 +1,773 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::{self, Command, Stdio};
+
+use crate::alloc;
+use crate::bench::{self, Verdict};
+use crate::check::{self, Outcome};
+use crate::generate::{Generator, Rng};
+use crate::image::Picture;
+use crate::viz::{self, Animation, Palette};
+use crate::Answer;
+
+/// Sent with submissions, as the Advent of Code automation guidelines ask.
+const USER_AGENT: &str = concat!(
+    "advent-of-code-solutions-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (answer submission via curl)"
+);
+
+const USAGE: &str = "usage: <year> [run|bench|compare|check|submit|animate|debug|picture|generate|scale|fuzz] [options]
+  --day <n>          only run the given day
+  --part <n>         only run the given part
+  --variant <name>   only run the given solution variant (run defaults to the
+                     default variant, bench and check run every variant)
+run:
+  --json             print the answers as a JSON array
+bench:
+  --samples <n>      timed runs per part (default 10)
+  --history <path>   benchmark history file (default bench_history.tsv)
+  --machine <name>   machine key for the history (default hostname)
+                     build with --features count-alloc to also report heap usage
+compare:
+  --baseline <rev>   revision to compare against (default previous in history)
+  --candidate <rev>  revision to compare (default current git revision)
+  --threshold <pct>  minimum relative change to report (default 5)
+  --alpha <p>        significance level for the noise test (default 0.05)
+check:
+  --inputs <dir>     run the selected day on every file in <dir>; the expected
+                     answer for <name>.txt is read from <name>.part1/<name>.part2
+submit:
+  posts the answer of the part selected by --day and --part, using the
+  session cookie in the AOC_SESSION environment variable
+animate:
+  --name <name>      simulation to animate (default the first for the day)
+  --steps <n>        maximum number of steps to simulate (default 1000)
+  --every <n>        only show every n-th step (default 1)
+  --fps <n>          frames per second for playback and gifs (default 10)
+  --dump <dir>       write the frames as text files instead of playing them
+  --ppm <dir>        write the frames as ppm images instead of playing them
+  --gif <path>       write the frames as an animated gif instead of playing them
+  --scale <n>        pixels per cell in images (default 4)
+debug:
+  --name <name>      simulation to step through interactively (h for commands)
+picture:
+  --name <name>      picture to render (default the first for the day)
+  --out <path>       .png, .pgm or .ppm file to write (default <year>-<day>-<name>.png)
+  --scale <n>        pixels per cell (default 1)
+generate:
+  --name <name>      input generator to use (default the first for the day)
+  --seed <n>         random seed (default 1)
+  --size <n>         size of the generated input (default 100)
+  --out <path>       write the input to a file instead of printing it
+scale:
+  times the selected parts of one day on generated inputs of each size
+  --name, --seed     as for generate
+  --sizes <n,n,..>   input sizes to time (default 25,50,100,200)
+  --samples <n>      timed runs per part and size (default 10)
+fuzz:
+  runs every variant of one day on generated inputs and reports panics and
+  variants that disagree with the default
+  --name, --seed, --size  as for generate, --seed is the first seed
+  --seeds <n>        number of inputs to try (default 20)";
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+pub struct Solution {
+    pub year: &'static str,
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub run: fn(&str) -> Answer,
+    pub input: &'static str,
+}
+
+impl Solution {
+    fn is_default(&self) -> bool {
+        self.variant == DEFAULT_VARIANT
+    }
+
+    fn label(&self) -> String {
+        label(self.day, self.part, self.variant)
+    }
+}
+
+fn label(day: u8, part: u8, variant: &str) -> String {
+    if variant == DEFAULT_VARIANT {
+        format!("Day {day} part {part}")
+    } else {
+        format!("Day {day} part {part} [{variant}]")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Bench,
+    Compare,
+    Check,
+    Submit,
+    Animate,
+    Debug,
+    Picture,
+    Generate,
+    Scale,
+    Fuzz,
+}
+
+#[derive(Debug)]
+struct Options {
+    mode: Mode,
+    json: bool,
+    day: Option<u8>,
+    part: Option<u8>,
+    variant: Option<String>,
+    samples: usize,
+    history: PathBuf,
+    machine: Option<String>,
+    baseline: Option<String>,
+    candidate: Option<String>,
+    threshold: f64,
+    alpha: f64,
+    inputs: Option<PathBuf>,
+    name: Option<String>,
+    steps: usize,
+    every: usize,
+    fps: f64,
+    dump: Option<PathBuf>,
+    ppm: Option<PathBuf>,
+    gif: Option<PathBuf>,
+    scale: Option<usize>,
+    out: Option<PathBuf>,
+    seed: u64,
+    size: usize,
+    sizes: Vec<usize>,
+    seeds: u64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            mode: Mode::Run,
+            json: false,
+            day: None,
+            part: None,
+            variant: None,
+            samples: 10,
+            history: PathBuf::from("bench_history.tsv"),
+            machine: None,
+            baseline: None,
+            candidate: None,
+            threshold: 5.0,
+            alpha: 0.05,
+            inputs: None,
+            name: None,
+            steps: 1000,
+            every: 1,
+            fps: 10.0,
+            dump: None,
+            ppm: None,
+            gif: None,
+            scale: None,
+            out: None,
+            seed: 1,
+            size: 100,
+            sizes: vec![25, 50, 100, 200],
+            seeds: 20,
+        }
+    }
+}
+
+fn value<T: std::str::FromStr>(
+    flag: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<T, String> {
+    let raw = args
+        .next()
+        .ok_or_else(|| format!("missing value for {flag}"))?;
+    raw.parse()
+        .map_err(|_| format!("invalid value for {flag}: {raw}"))
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "run" => options.mode = Mode::Run,
+                "bench" => options.mode = Mode::Bench,
+                "compare" => options.mode = Mode::Compare,
+                "check" => options.mode = Mode::Check,
+                "submit" => options.mode = Mode::Submit,
+                "animate" => options.mode = Mode::Animate,
+                "debug" => options.mode = Mode::Debug,
+                "picture" => options.mode = Mode::Picture,
+                "generate" => options.mode = Mode::Generate,
+                "scale" => options.mode = Mode::Scale,
+                "fuzz" => options.mode = Mode::Fuzz,
+                "--json" => options.json = true,
+                "--day" => options.day = Some(value(&arg, &mut args)?),
+                "--part" => options.part = Some(value(&arg, &mut args)?),
+                "--variant" => options.variant = Some(value(&arg, &mut args)?),
+                "--samples" => options.samples = value(&arg, &mut args)?,
+                "--history" => options.history = value(&arg, &mut args)?,
+                "--machine" => options.machine = Some(value(&arg, &mut args)?),
+                "--baseline" => options.baseline = Some(value(&arg, &mut args)?),
+                "--candidate" => options.candidate = Some(value(&arg, &mut args)?),
+                "--threshold" => options.threshold = value(&arg, &mut args)?,
+                "--alpha" => options.alpha = value(&arg, &mut args)?,
+                "--inputs" => options.inputs = Some(value(&arg, &mut args)?),
+                "--name" => options.name = Some(value(&arg, &mut args)?),
+                "--steps" => options.steps = value(&arg, &mut args)?,
+                "--every" => options.every = value(&arg, &mut args)?,
+                "--fps" => options.fps = value(&arg, &mut args)?,
+                "--dump" => options.dump = Some(value(&arg, &mut args)?),
+                "--ppm" => options.ppm = Some(value(&arg, &mut args)?),
+                "--gif" => options.gif = Some(value(&arg, &mut args)?),
+                "--scale" => options.scale = Some(value(&arg, &mut args)?),
+                "--out" => options.out = Some(value(&arg, &mut args)?),
+                "--seed" => options.seed = value(&arg, &mut args)?,
+                "--size" => options.size = value(&arg, &mut args)?,
+                "--sizes" => {
+                    let raw: String = value(&arg, &mut args)?;
+                    options.sizes = raw
+                        .split(',')
+                        .map(|size| {
+                            size.trim()
+                                .parse()
+                                .map_err(|_| format!("invalid value for {arg}: {raw}"))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "--seeds" => options.seeds = value(&arg, &mut args)?,
+                _ => return Err(format!("unknown argument: {arg}")),
+            }
+        }
+        Ok(options)
+    }
+
+    fn selects(&self, solution: &Solution) -> bool {
+        self.day.is_none_or(|day| day == solution.day)
+            && self.part.is_none_or(|part| part == solution.part)
+            && self.variant.as_ref().map_or(
+                !matches!(self.mode, Mode::Run | Mode::Submit) || solution.is_default(),
+                |variant| variant == solution.variant,
+            )
+    }
+
+    fn machine(&self) -> String {
+        self.machine
+            .as_deref()
+            .map_or_else(bench::machine_name, bench::sanitise)
+    }
+}
+
+fn print_answer(label: &str, answer: &Answer) {
+    if let Some(letters) = answer.letters() {
+        println!("{label}: {letters}\n{answer}");
+    } else if answer.is_multiline() {
+        println!("{label}:\n{answer}");
+    } else {
+        println!("{label}: {answer}");
+    }
+}
+
+fn run_all(solutions: &[&Solution], options: &Options) {
+    if options.json {
+        let entries: Vec<_> = solutions
+            .iter()
+            .map(|solution| {
+                format!(
+                    "{{\"year\":\"{}\",\"day\":{},\"part\":{},\"variant\":\"{}\",\"answer\":{}}}",
+                    solution.year,
+                    solution.day,
+                    solution.part,
+                    solution.variant,
+                    (solution.run)(solution.input).to_json()
+                )
+            })
+            .collect();
+        println!("[{}]", entries.join(",\n "));
+    } else {
+        for solution in solutions {
+            print_answer(&solution.label(), &(solution.run)(solution.input));
+        }
+    }
+}
+
+fn bench_all(solutions: &[&Solution], options: &Options) {
+    let revision = bench::git_revision();
+    let machine = options.machine();
+    let records: Vec<_> = solutions
+        .iter()
+        .map(|solution| {
+            let samples = bench::measure(
+                || {
+                    std::hint::black_box((solution.run)(std::hint::black_box(solution.input)));
+                },
+                options.samples,
+            );
+            let memory = if alloc::enabled() {
+                let (_, stats) = alloc::measure(|| (solution.run)(solution.input));
+                format!(
+                    " (peak {}, {} allocations, {} allocated)",
+                    alloc::format_bytes(stats.peak),
+                    stats.allocations,
+                    alloc::format_bytes(stats.allocated)
+                )
+            } else {
+                String::new()
+            };
+            println!(
+                "{}: {}{memory}",
+                solution.label(),
+                bench::format_nanos(bench::median(&samples))
+            );
+            bench::Record {
+                revision: revision.clone(),
+                machine: machine.clone(),
+                year: solution.year.to_string(),
+                day: solution.day,
+                part: solution.part,
+                variant: solution.variant.to_string(),
+                samples,
+            }
+        })
+        .collect();
+    bench::append_history(&options.history, &records).expect("could not write history");
+    println!(
+        "Recorded {} results for {revision} on {machine} in {}",
+        records.len(),
+        options.history.display()
+    );
+}
+
+fn compare_all(solutions: &[&Solution], options: &Options) {
+    let Some(year) = solutions.first().map(|solution| solution.year) else {
+        return;
+    };
+    let machine = options.machine();
+    let history: Vec<_> = bench::load_history(&options.history)
+        .expect("could not read history")
+        .into_iter()
+        .filter(|record| {
+            record.year == year
+                && record.machine == machine
+                && solutions.iter().any(|solution| {
+                    (solution.day, solution.part, solution.variant)
+                        == (record.day, record.part, record.variant.as_str())
+                })
+        })
+        .collect();
+    let candidate = options
+        .candidate
+        .clone()
+        .unwrap_or_else(bench::git_revision);
+    let Some(baseline) = options.baseline.clone().or_else(|| {
+        history
+            .iter()
+            .rev()
+            .find(|record| record.revision != candidate)
+            .map(|record| record.revision.clone())
+    }) else {
+        eprintln!(
+            "no baseline revision found in {}",
+            options.history.display()
+        );
+        process::exit(1);
+    };
+    let of_revision = |revision: &str| {
+        history
+            .iter()
+            .filter(|record| record.revision == revision)
+            .collect::<Vec<_>>()
+    };
+    let comparisons = bench::compare(
+        &of_revision(&baseline),
+        &of_revision(&candidate),
+        options.threshold / 100.0,
+        options.alpha,
+    );
+    if comparisons.is_empty() {
+        eprintln!("no results shared by {baseline} and {candidate} on {machine}");
+        process::exit(1);
+    }
+    println!("Comparing {candidate} against {baseline} on {machine}");
+    for comparison in comparisons {
+        println!(
+            "{}: {} -> {} ({:+.1}%, p={:.3}) {}",
+            label(comparison.day, comparison.part, &comparison.variant),
+            bench::format_nanos(comparison.baseline),
+            bench::format_nanos(comparison.candidate),
+            comparison.change * 100.0,
+            comparison.p_value,
+            match comparison.verdict {
+                Verdict::Faster => "faster",
+                Verdict::Slower => "SLOWER",
+                Verdict::Unchanged => "unchanged",
+            }
+        );
+    }
+}
+
+fn check_all(solutions: &[&Solution], options: &Options) {
+    let Some(dir) = &options.inputs else {
+        eprintln!("check needs --inputs <dir>\n{USAGE}");
+        process::exit(2);
+    };
+    if options.day.is_none() {
+        eprintln!("check needs --day <n>\n{USAGE}");
+        process::exit(2);
+    }
+    let cases = check::load_inputs(dir).expect("could not read inputs");
+    let mut failures = 0;
+    for case in &cases {
+        let mut defaults = HashMap::new();
+        for solution in solutions {
+            let expected = case
+                .expected(solution.part)
+                .expect("could not read expected answer");
+            let outcome = check::check(solution.run, &case.input, expected);
+            let disagreement = match (&outcome, defaults.get(&solution.part)) {
+                (Outcome::Unverified(actual), Some(default)) if actual != default => Some(default),
+                _ => None,
+            };
+            if outcome.is_failure() || disagreement.is_some() {
+                failures += 1;
+            }
+            let label = format!("{} {}", case.path.display(), solution.label());
+            match (&outcome, disagreement) {
+                (Outcome::Unverified(actual), Some(default)) => {
+                    print_answer(&format!("{label}: DISAGREES with default"), actual);
+                    print_answer("  default", default);
+                }
+                (Outcome::Correct(actual), _) => print_answer(&format!("{label}: ok"), actual),
+                (Outcome::Unverified(actual), _) => {
+                    print_answer(&format!("{label}: no expected answer"), actual);
+                }
+                (Outcome::Wrong { expected, actual }, _) => {
+                    print_answer(&format!("{label}: WRONG"), actual);
+                    print_answer("  expected", &Answer::Text(expected.clone()));
+                }
+                (Outcome::Panicked(message), _) => println!("{label}: PANICKED: {message}"),
+            }
+            if let (true, Some(actual)) = (solution.is_default(), outcome.answer()) {
+                defaults.insert(solution.part, actual.clone());
+            }
+        }
+    }
+    println!(
+        "{failures} failures over {} inputs in {}",
+        cases.len(),
+        dir.display()
+    );
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn find_generator<'a>(generators: &'a [Generator], options: &Options) -> &'a Generator {
+    if options.day.is_none() {
+        eprintln!("generated inputs need --day <n>\n{USAGE}");
+        process::exit(2);
+    }
+    let Some(generator) = generators.iter().find(|generator| {
+        options.day.is_none_or(|day| day == generator.day)
+            && options
+                .name
+                .as_ref()
+                .is_none_or(|name| name == generator.name)
+    }) else {
+        eprintln!(
+            "no matching generator, available: {}",
+            generators
+                .iter()
+                .map(|generator| format!("day {} {}", generator.day, generator.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        process::exit(2);
+    };
+    generator
+}
+
+fn generate(generators: &[Generator], options: &Options) {
+    let generator = find_generator(generators, options);
+    let input = (generator.generate)(&mut Rng::new(options.seed), options.size);
+    if let Some(path) = &options.out {
+        std::fs::write(path, &input).expect("could not write input");
+        println!(
+            "Wrote {} input of size {} with seed {} to {}",
+            generator.name,
+            options.size,
+            options.seed,
+            path.display()
+        );
+    } else {
+        print!("{input}");
+    }
+}
+
+fn scale_all(solutions: &[&Solution], generators: &[Generator], options: &Options) {
+    let generator = find_generator(generators, options);
+    for size in &options.sizes {
+        let input = (generator.generate)(&mut Rng::new(options.seed), *size);
+        for solution in solutions {
+            let timed = check::catch(|| {
+                bench::measure(
+                    || {
+                        std::hint::black_box((solution.run)(std::hint::black_box(&input)));
+                    },
+                    options.samples,
+                )
+            });
+            match timed {
+                Ok(samples) => println!(
+                    "{} size {size}: {}",
+                    solution.label(),
+                    bench::format_nanos(bench::median(&samples))
+                ),
+                Err(message) => println!("{} size {size}: PANICKED: {message}", solution.label()),
+            }
+        }
+    }
+}
+
+fn fuzz_all(solutions: &[&Solution], generators: &[Generator], options: &Options) {
+    let generator = find_generator(generators, options);
+    let mut failures = 0;
+    for seed in options.seed..options.seed + options.seeds {
+        let input = (generator.generate)(&mut Rng::new(seed), options.size);
+        let mut defaults = HashMap::new();
+        for solution in solutions {
+            let label = format!("seed {seed} {}", solution.label());
+            match check::catch(|| (solution.run)(&input)) {
+                Ok(answer) if solution.is_default() => {
+                    defaults.insert(solution.part, answer);
+                }
+                Ok(answer) => {
+                    if let Some(default) = defaults.get(&solution.part) {
+                        if *default != answer {
+                            failures += 1;
+                            print_answer(&format!("{label}: DISAGREES with default"), &answer);
+                            print_answer("  default", default);
+                        }
+                    }
+                }
+                Err(message) => {
+                    failures += 1;
+                    println!("{label}: PANICKED: {message}");
+                }
+            }
+        }
+    }
+    println!(
+        "{failures} failures over {} {} inputs of size {}",
+        options.seeds, generator.name, options.size
+    );
+    if failures > 0 {
+        println!(
+            "reproduce an input with: generate --day <n> --name <name> --size <n> --seed <seed>"
+        );
+        process::exit(1);
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn submit(solutions: &[&Solution]) {
+    let [solution] = solutions else {
+        eprintln!("submit needs exactly one part, select it with --day and --part\n{USAGE}");
+        process::exit(2);
+    };
+    let answer = (solution.run)(solution.input);
+    let Some(submission) = answer.submission() else {
+        eprintln!("a {} answer cannot be submitted:\n{answer}", answer.kind());
+        process::exit(1);
+    };
+    let Ok(session) = std::env::var("AOC_SESSION") else {
+        eprintln!("set AOC_SESSION to submit {submission}");
+        process::exit(1);
+    };
+    // The cookie goes through a config on stdin to keep it off the command
+    // line, where other users could read it.
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--config", "-"])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--data", &format!("level={}", solution.part)])
+        .args(["--data-urlencode", &format!("answer={submission}")])
+        .arg(format!(
+            "https://adventofcode.com/{}/day/{}/answer",
+            solution.year.trim_start_matches("ac"),
+            solution.day
+        ))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not run curl");
+    curl.stdin
+        .take()
+        .expect("curl stdin is piped")
+        .write_all(format!("cookie = \"session={session}\"\n").as_bytes())
+        .expect("could not pass the session to curl");
+    let output = curl.wait_with_output().expect("could not run curl");
+    if !output.status.success() {
+        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        process::exit(1);
+    }
+    let body = String::from_utf8_lossy(&output.stdout);
+    let article = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body.as_ref(), |(article, _)| article);
+    println!("{}: submitted {submission}", solution.label());
+    println!("{}", strip_tags(article));
+}
+
+fn find_animation<'a>(animations: &'a [Animation], options: &Options) -> &'a Animation {
+    let Some(animation) = animations.iter().find(|animation| {
+        options.day.is_none_or(|day| day == animation.day)
+            && options
+                .name
+                .as_ref()
+                .is_none_or(|name| name == animation.name)
+    }) else {
+        eprintln!(
+            "no matching simulation, available: {}",
+            animations
+                .iter()
+                .map(|animation| format!("day {} {}", animation.day, animation.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        process::exit(2);
+    };
+    animation
+}
+
+fn debug(animations: &[Animation], options: &Options) {
+    let animation = find_animation(animations, options);
+    let mut simulation = (animation.build)(animation.input);
+    if let Err(e) = viz::debug(&mut *simulation, io::stdin().lock(), io::stdout().lock()) {
+        eprintln!("debugger failed: {e}");
+        process::exit(1);
+    }
+}
+
+fn animate(animations: &[Animation], options: &Options) {
+    let animation = find_animation(animations, options);
+    let mut simulation = (animation.build)(animation.input);
+    if options.dump.is_none() && options.ppm.is_none() && options.gif.is_none() {
+        viz::play(&mut *simulation, options.steps, options.every, options.fps);
+        return;
+    }
+    let frames = viz::record(&mut *simulation, options.steps, options.every);
+    let palette = Palette::default();
+    if let Some(dir) = &options.dump {
+        viz::dump_text(&frames, dir).expect("could not write frames");
+        println!("Wrote {} frames to {}", frames.len(), dir.display());
+    }
+    if let Some(dir) = &options.ppm {
+        viz::dump_ppm(&frames, dir, &palette, options.scale.unwrap_or(4))
+            .expect("could not write frames");
+        println!("Wrote {} images to {}", frames.len(), dir.display());
+    }
+    if let Some(path) = &options.gif {
+        let file = File::create(path).expect("could not create gif");
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let delay = (100.0 / options.fps.max(0.01)).round().clamp(1.0, 65535.0) as u16;
+        viz::write_gif(
+            BufWriter::new(file),
+            &frames,
+            &palette,
+            options.scale.unwrap_or(4),
+            delay,
+        )
+        .expect("could not write gif");
+        println!("Wrote {} frames to {}", frames.len(), path.display());
+    }
+}
+
+fn picture(pictures: &[Picture], options: &Options) {
+    let Some(picture) = pictures.iter().find(|picture| {
+        options.day.is_none_or(|day| day == picture.day)
+            && options
+                .name
+                .as_ref()
+                .is_none_or(|name| name == picture.name)
+    }) else {
+        eprintln!(
+            "no matching picture, available: {}",
+            pictures
+                .iter()
+                .map(|picture| format!("day {} {}", picture.day, picture.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        process::exit(2);
+    };
+    let path = options.out.clone().unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "{}-{:02}-{}.png",
+            picture.year, picture.day, picture.name
+        ))
+    });
+    let image = (picture.render)(picture.input).scaled(options.scale.unwrap_or(1));
+    if let Err(e) = image.save(&path) {
+        eprintln!("could not write {}: {e}", path.display());
+        process::exit(1);
+    }
+    println!(
+        "Wrote {}x{} image to {}",
+        image.width(),
+        image.height(),
+        path.display()
+    );
+}
+
+pub fn run(
+    solutions: &[Solution],
+    animations: &[Animation],
+    pictures: &[Picture],
+    generators: &[Generator],
+) {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+    let selected: Vec<_> = solutions
+        .iter()
+        .filter(|solution| options.selects(solution))
+        .collect();
+    match options.mode {
+        Mode::Run => run_all(&selected, &options),
+        Mode::Bench => bench_all(&selected, &options),
+        Mode::Compare => compare_all(&selected, &options),
+        Mode::Check => check_all(&selected, &options),
+        Mode::Submit => submit(&selected),
+        Mode::Animate => animate(animations, &options),
+        Mode::Debug => debug(animations, &options),
+        Mode::Picture => picture(pictures, &options),
+        Mode::Generate => generate(generators, &options),
+        Mode::Scale => scale_all(&selected, generators, &options),
+        Mode::Fuzz => fuzz_all(&selected, generators, &options),
+    }
+}
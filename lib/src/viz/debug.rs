@@ -0,0 +1,247 @@
+use std::io::{self, BufRead, Write};
+
+use super::Simulation;
+
+const HELP: &str = "commands:
+  <enter>, s, step        advance one step
+  s <n>, j <n>, jump <n>  advance n steps
+  u, until <watch> <op> <value>
+                          run until a watched value matches (op: == != < <= > >=)
+  u end, until end        run until the simulation finishes
+  q, quit                 leave the debugger";
+
+const UNTIL_LIMIT: usize = 100_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Op {
+    fn parse(op: &str) -> Option<Self> {
+        Some(match op {
+            "==" | "=" => Self::Eq,
+            "!=" => Self::Ne,
+            "<" => Self::Lt,
+            "<=" => Self::Le,
+            ">" => Self::Gt,
+            ">=" => Self::Ge,
+            _ => return None,
+        })
+    }
+
+    const fn test(self, left: i64, right: i64) -> bool {
+        match self {
+            Self::Eq => left == right,
+            Self::Ne => left != right,
+            Self::Lt => left < right,
+            Self::Le => left <= right,
+            Self::Gt => left > right,
+            Self::Ge => left >= right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Until(String, Op, i64),
+    UntilEnd,
+    Help,
+    Quit,
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let count = |word: &str| {
+        word.parse()
+            .map_err(|_| format!("expected a number of steps, got {word}"))
+    };
+    match words.as_slice() {
+        [] | ["s" | "step"] => Ok(Command::Step(1)),
+        ["s" | "step" | "j" | "jump", n] => count(n).map(Command::Step),
+        ["u" | "until", "end"] => Ok(Command::UntilEnd),
+        ["u" | "until", watch, op, value] => {
+            let op = Op::parse(op).ok_or_else(|| format!("unknown comparison {op}"))?;
+            let value = value
+                .parse()
+                .map_err(|_| format!("expected a number, got {value}"))?;
+            Ok(Command::Until((*watch).to_string(), op, value))
+        }
+        ["h" | "help" | "?"] => Ok(Command::Help),
+        ["q" | "quit"] => Ok(Command::Quit),
+        _ => Err(format!("unknown command: {line}")),
+    }
+}
+
+fn watch(simulation: &dyn Simulation, name: &str) -> Option<i64> {
+    simulation
+        .watches()
+        .into_iter()
+        .find_map(|(watch, value)| (watch == name).then_some(value))
+}
+
+struct Position {
+    step: usize,
+    finished: bool,
+}
+
+impl Position {
+    fn advance(
+        &mut self,
+        simulation: &mut dyn Simulation,
+        until: impl Fn(&dyn Simulation) -> bool,
+        limit: usize,
+    ) {
+        for _ in 0..limit {
+            if self.finished || until(simulation) {
+                return;
+            }
+            self.finished = !simulation.step();
+            if !self.finished {
+                self.step += 1;
+            }
+        }
+    }
+}
+
+fn show<W: Write>(out: &mut W, simulation: &dyn Simulation, position: &Position) -> io::Result<()> {
+    let Position { step, finished } = *position;
+    writeln!(out, "{}", simulation.frame().join("\n"))?;
+    let watches: Vec<String> = simulation
+        .watches()
+        .into_iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect();
+    writeln!(out, "step {step} {}", watches.join(" "))?;
+    if finished {
+        writeln!(out, "finished")
+    } else if let Some(next) = simulation.next_step() {
+        writeln!(out, "next: {next}")
+    } else {
+        Ok(())
+    }
+}
+
+/// Reads commands from `input` and steps through the simulation, showing the
+/// state on `out` after each command.
+pub fn debug<R: BufRead, W: Write>(
+    simulation: &mut dyn Simulation,
+    input: R,
+    mut out: W,
+) -> io::Result<()> {
+    let mut position = Position {
+        step: 0,
+        finished: false,
+    };
+    show(&mut out, simulation, &position)?;
+    let mut lines = input.lines();
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            return Ok(());
+        };
+        let command = match parse_command(&line) {
+            Ok(command) => command,
+            Err(e) => {
+                writeln!(out, "{e} (h for help)")?;
+                continue;
+            }
+        };
+        match command {
+            Command::Step(n) => position.advance(simulation, |_| false, n),
+            Command::UntilEnd => position.advance(simulation, |_| false, UNTIL_LIMIT),
+            Command::Until(name, op, value) => {
+                if watch(simulation, &name).is_none() {
+                    writeln!(out, "no watch named {name}")?;
+                    continue;
+                }
+                let met = |simulation: &dyn Simulation| {
+                    watch(simulation, &name).is_some_and(|current| op.test(current, value))
+                };
+                position.advance(simulation, met, UNTIL_LIMIT);
+                if !met(simulation) {
+                    writeln!(out, "condition on {name} not reached")?;
+                }
+            }
+            Command::Help => {
+                writeln!(out, "{HELP}")?;
+                continue;
+            }
+            Command::Quit => return Ok(()),
+        }
+        show(&mut out, simulation, &position)?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::viz::Frame;
+
+    struct Counter {
+        value: i64,
+        limit: i64,
+    }
+
+    impl Simulation for Counter {
+        fn frame(&self) -> Frame {
+            vec![format!("value {}", self.value)]
+        }
+
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn next_step(&self) -> Option<String> {
+            Some(format!("increment to {}", self.value + 1))
+        }
+
+        fn watches(&self) -> Vec<(&'static str, i64)> {
+            vec![("value", self.value)]
+        }
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command(""), Ok(Command::Step(1)));
+        assert_eq!(parse_command("j 12"), Ok(Command::Step(12)));
+        assert_eq!(
+            parse_command("until x >= -3"),
+            Ok(Command::Until("x".to_string(), Op::Ge, -3))
+        );
+        assert!(parse_command("until x ~ 3").is_err());
+    }
+
+    #[test]
+    fn test_debug_session() {
+        let mut counter = Counter {
+            value: 0,
+            limit: 10,
+        };
+        let mut out = vec![];
+        debug(
+            &mut counter,
+            "\nj 3\nu value >= 7\nfoo\nu end\nq\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("step 1 value=1\nnext: increment to 2"));
+        assert!(out.contains("step 4 value=4"));
+        assert!(out.contains("step 7 value=7"));
+        assert!(out.contains("unknown command: foo"));
+        assert!(out.contains("step 10 value=10\nfinished"));
+        assert_eq!(counter.value, 10);
+    }
+}
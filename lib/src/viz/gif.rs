@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use super::{cell, frame_size, Frame, Palette};
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    const fn new() -> Self {
+        Self {
+            bytes: vec![],
+            buffer: 0,
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push((self.buffer & 0xff) as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push((self.buffer & 0xff) as u8);
+        }
+        self.bytes
+    }
+}
+
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear: u16 = 1 << min_code_size;
+    let end = clear + 1;
+    let mut writer = BitWriter::new();
+    let mut code_size = min_code_size + 1;
+    let mut next_code = end + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    writer.write(clear, code_size);
+    let Some((&first, rest)) = indices.split_first() else {
+        writer.write(end, code_size);
+        return writer.finish();
+    };
+    let mut prefix = u16::from(first);
+    for &index in rest {
+        if let Some(&code) = table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        writer.write(prefix, code_size);
+        if next_code < 4096 {
+            table.insert((prefix, index), next_code);
+            next_code += 1;
+            if next_code > 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+        } else {
+            writer.write(clear, code_size);
+            table.clear();
+            code_size = min_code_size + 1;
+            next_code = end + 1;
+        }
+        prefix = u16::from(index);
+    }
+    writer.write(prefix, code_size);
+    writer.write(end, code_size);
+    writer.finish()
+}
+
+pub fn write_gif<W: Write>(
+    mut out: W,
+    frames: &[Frame],
+    palette: &Palette,
+    scale: usize,
+    delay_centis: u16,
+) -> io::Result<()> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let (width, height) = frame_size(frames);
+    let (width, height) = (width * scale, height * scale);
+    let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(invalid("frames are too large for a gif"));
+    };
+    let mut colours: Vec<[u8; 3]> = vec![palette.colour(' ')];
+    let mut lookup: HashMap<char, u8> = HashMap::new();
+    for c in frames.iter().flatten().flat_map(|row| row.chars()) {
+        if lookup.contains_key(&c) {
+            continue;
+        }
+        let colour = palette.colour(c);
+        let index = if let Some(index) = colours.iter().position(|known| *known == colour) {
+            index
+        } else {
+            colours.push(colour);
+            colours.len() - 1
+        };
+        let index = u8::try_from(index).map_err(|_| invalid("more than 256 colours"))?;
+        lookup.insert(c, index);
+    }
+    let table_bits = (colours.len().max(2) - 1).ilog2() as u8 + 1;
+    colours.resize(1 << table_bits, [0, 0, 0]);
+
+    out.write_all(b"GIF89a")?;
+    out.write_all(&w.to_le_bytes())?;
+    out.write_all(&h.to_le_bytes())?;
+    out.write_all(&[0x80 | (table_bits - 1), 0, 0])?;
+    for colour in &colours {
+        out.write_all(colour)?;
+    }
+    out.write_all(&[0x21, 0xff, 0x0b])?;
+    out.write_all(b"NETSCAPE2.0")?;
+    out.write_all(&[0x03, 0x01, 0x00, 0x00, 0x00])?;
+
+    let min_code_size = table_bits.max(2);
+    for frame in frames {
+        out.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        out.write_all(&delay_centis.to_le_bytes())?;
+        out.write_all(&[0x00, 0x00])?;
+        out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        out.write_all(&w.to_le_bytes())?;
+        out.write_all(&h.to_le_bytes())?;
+        out.write_all(&[0x00])?;
+        let indices: Vec<u8> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                lookup
+                    .get(&cell(frame, x / scale, y / scale))
+                    .copied()
+                    .unwrap_or(0)
+            })
+            .collect();
+        out.write_all(&[min_code_size])?;
+        for block in lzw_encode(&indices, min_code_size).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0x00])?;
+    }
+    out.write_all(&[0x3b])?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lzw_decode(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let end = clear + 1;
+        let mut code_size = min_code_size + 1;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([vec![], vec![]]);
+        };
+        reset(&mut table);
+        let (mut buffer, mut bits, mut position) = (0u32, 0u8, 0);
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = vec![];
+        loop {
+            while bits < code_size {
+                buffer |= u32::from(bytes[position]) << bits;
+                position += 1;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as usize;
+            buffer >>= code_size;
+            bits -= code_size;
+            if code == clear {
+                reset(&mut table);
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+            let entry = match (&previous, table.get(code)) {
+                (_, Some(entry)) if code < table.len() => entry.clone(),
+                (Some(prev), _) => {
+                    let mut entry = prev.clone();
+                    entry.push(prev[0]);
+                    entry
+                }
+                (None, _) => panic!("invalid code"),
+            };
+            if let Some(prev) = previous {
+                if table.len() < 4096 {
+                    let mut new = prev;
+                    new.push(entry[0]);
+                    table.push(new);
+                    if table.len() == 1 << code_size && code_size < 12 {
+                        code_size += 1;
+                    }
+                }
+            }
+            out.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let mut seed = 12345u32;
+        let noisy: Vec<u8> = (0..20_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((seed >> 16) % 7) as u8
+            })
+            .collect();
+        let repetitive: Vec<u8> = (0..20_000).map(|i| ((i / 37) % 3) as u8).collect();
+        for data in [noisy, repetitive, vec![1], vec![]] {
+            assert_eq!(lzw_decode(&lzw_encode(&data, 3), 3), data);
+        }
+    }
+
+    #[test]
+    fn test_gif_structure() {
+        let frames = vec![vec!["#.".to_string(), ".@".to_string()]; 2];
+        let mut bytes = vec![];
+        write_gif(&mut bytes, &frames, &Palette::default(), 2, 10).unwrap();
+        assert_eq!(&bytes[..6], b"GIF89a");
+        assert_eq!(&bytes[6..10], &[4, 0, 4, 0]);
+        assert_eq!(bytes.last(), Some(&0x3b));
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::image::Image;
+
+mod debug;
+mod gif;
+
+pub use debug::debug;
+pub use gif::write_gif;
+
+pub type Frame = Vec<String>;
+
+pub trait Simulation {
+    fn frame(&self) -> Frame;
+
+    /// Advances the simulation by one step, returning `false` once it has finished.
+    fn step(&mut self) -> bool;
+
+    /// Describes what the next call to `step` will do, for the debugger.
+    fn next_step(&self) -> Option<String> {
+        None
+    }
+
+    /// Named values the debugger shows and can break on.
+    fn watches(&self) -> Vec<(&'static str, i64)> {
+        vec![]
+    }
+}
+
+pub struct Animation {
+    pub year: &'static str,
+    pub day: u8,
+    pub name: &'static str,
+    pub build: fn(&str) -> Box<dyn Simulation>,
+    pub input: &'static str,
+}
+
+pub fn record(simulation: &mut dyn Simulation, steps: usize, every: usize) -> Vec<Frame> {
+    let every = every.max(1);
+    let mut frames = vec![simulation.frame()];
+    let mut recorded = true;
+    for i in 1..=steps {
+        if !simulation.step() {
+            break;
+        }
+        recorded = i % every == 0;
+        if recorded {
+            frames.push(simulation.frame());
+        }
+    }
+    if !recorded {
+        frames.push(simulation.frame());
+    }
+    frames
+}
+
+pub fn play(simulation: &mut dyn Simulation, steps: usize, every: usize, fps: f64) {
+    let delay = Duration::from_secs_f64(1.0 / fps.max(0.01));
+    let every = every.max(1);
+    let mut stdout = io::stdout().lock();
+    let mut show = |step: usize, frame: &Frame| {
+        let _ = write!(stdout, "\x1b[H\x1b[2J{}\nstep {step}\n", frame.join("\n"));
+        let _ = stdout.flush();
+        thread::sleep(delay);
+    };
+    show(0, &simulation.frame());
+    for i in 1..=steps {
+        if !simulation.step() {
+            break;
+        }
+        if i % every == 0 {
+            show(i, &simulation.frame());
+        }
+    }
+}
+
+pub fn dump_text(frames: &[Frame], dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (i, frame) in frames.iter().enumerate() {
+        fs::write(
+            dir.join(format!("frame_{i:05}.txt")),
+            frame.join("\n") + "\n",
+        )?;
+    }
+    Ok(())
+}
+
+pub fn dump_ppm(frames: &[Frame], dir: &Path, palette: &Palette, scale: usize) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let (width, height) = frame_size(frames);
+    for (i, frame) in frames.iter().enumerate() {
+        let image = Image::from_fn(width, height, |x, y| palette.colour(cell(frame, x, y)));
+        let file = File::create(dir.join(format!("frame_{i:05}.ppm")))?;
+        image.scaled(scale).write_ppm(BufWriter::new(file))?;
+    }
+    Ok(())
+}
+
+pub fn frame_size(frames: &[Frame]) -> (usize, usize) {
+    let width = frames
+        .iter()
+        .flatten()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let height = frames.iter().map(Vec::len).max().unwrap_or(0);
+    (width, height)
+}
+
+fn cell(frame: &Frame, x: usize, y: usize) -> char {
+    frame
+        .get(y)
+        .and_then(|row| row.chars().nth(x))
+        .unwrap_or(' ')
+}
+
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colours: HashMap<char, [u8; 3]>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            colours: [
+                (' ', [16, 16, 32]),
+                ('.', [16, 16, 32]),
+                ('#', [200, 200, 200]),
+                ('@', [230, 60, 60]),
+                ('O', [230, 190, 60]),
+                ('[', [230, 190, 60]),
+                (']', [230, 190, 60]),
+                ('*', [60, 200, 90]),
+                ('|', [120, 120, 140]),
+                ('-', [120, 120, 140]),
+                ('+', [120, 120, 140]),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+}
+
+impl Palette {
+    #[must_use]
+    pub fn with(mut self, c: char, colour: [u8; 3]) -> Self {
+        self.colours.insert(c, colour);
+        self
+    }
+
+    pub fn colour(&self, c: char) -> [u8; 3] {
+        self.colours.get(&c).copied().unwrap_or_else(|| {
+            let code = c as u32;
+            [
+                (code.wrapping_mul(97) % 200 + 55) as u8,
+                (code.wrapping_mul(57) % 200 + 55) as u8,
+                (code.wrapping_mul(31) % 200 + 55) as u8,
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Counter {
+        value: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn frame(&self) -> Frame {
+            vec!["#".repeat(self.value)]
+        }
+
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let frames = record(&mut Counter { value: 0, limit: 5 }, 100, 2);
+        assert_eq!(
+            frames,
+            [
+                vec![String::new()],
+                vec!["##".into()],
+                vec!["####".into()],
+                vec!["#####".into()]
+            ]
+        );
+        let frames = record(&mut Counter { value: 0, limit: 5 }, 3, 1);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frame_size(&frames), (3, 1));
+    }
+}